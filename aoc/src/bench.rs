@@ -0,0 +1,152 @@
+use std::fs;
+use std::time::{Duration, Instant};
+
+use eyre::{Result, WrapErr};
+use serde::Serialize;
+
+use crate::{days, inputs, Format};
+
+#[derive(Debug, Serialize)]
+struct BenchReport {
+    iterations: usize,
+    warmup: usize,
+    results: Vec<PhaseTimings>,
+}
+
+// Timings of a single phase (parse, part 1 or part 2) of one day, in nanoseconds
+#[derive(Debug, Serialize)]
+struct PhaseTimings {
+    day: u8,
+    variant: String,
+    phase: &'static str,
+    min_ns: u64,
+    median_ns: u64,
+    p95_ns: u64,
+}
+
+impl PhaseTimings {
+    fn new(day: u8, variant: &str, phase: &'static str, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        PhaseTimings {
+            day,
+            variant: variant.to_string(),
+            phase,
+            min_ns: nanos(samples[0]),
+            median_ns: nanos(percentile(&samples, 50)),
+            p95_ns: nanos(percentile(&samples, 95)),
+        }
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos() as u64
+}
+
+// Nearest-rank percentile of already sorted samples
+fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+    let rank = (sorted.len() * percent).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+// Time `f` over the given number of iterations after discarding `warmup` runs
+fn sample<T>(
+    iterations: usize,
+    warmup: usize,
+    mut f: impl FnMut() -> Result<T>,
+) -> Result<Vec<Duration>> {
+    for _ in 0..warmup {
+        f()?;
+    }
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let now = Instant::now();
+        f()?;
+        samples.push(now.elapsed());
+    }
+    Ok(samples)
+}
+
+fn bench_day(
+    day: u8,
+    variant: &str,
+    input: &str,
+    iterations: usize,
+    warmup: usize,
+) -> Result<Vec<PhaseTimings>> {
+    let solver = days::solver(day)?;
+    let parse = sample(iterations, warmup, || solver.parse(input))?;
+    let parsed = solver.parse(input)?;
+    let part_1 = sample(iterations, warmup, || solver.solve(parsed.as_ref(), 1))?;
+    let part_2 = sample(iterations, warmup, || solver.solve(parsed.as_ref(), 2))?;
+
+    Ok(vec![
+        PhaseTimings::new(day, variant, "parse", parse),
+        PhaseTimings::new(day, variant, "part_1", part_1),
+        PhaseTimings::new(day, variant, "part_2", part_2),
+    ])
+}
+
+// Benchmark the parse, part 1 and part 2 phases of each day separately. Days without the requested
+//      input variant, or whose solver fails, are skipped with a note on stderr.
+pub fn bench(
+    day: Option<u8>,
+    variant: &str,
+    iterations: usize,
+    warmup: usize,
+    format: Format,
+    output: Option<&str>,
+) -> Result<()> {
+    let days: Vec<u8> = match day {
+        Some(day) => vec![day],
+        None => (1..=days::DAYS).collect(),
+    };
+
+    let mut results = Vec::new();
+    for day in days {
+        let path = inputs::variant_path(day, variant)?;
+        let Ok(input) = inputs::read_file(&path) else {
+            eprintln!("Skipping day {}, no input at {}", day, path.display());
+            continue;
+        };
+        match days::guarded(|| bench_day(day, variant, &input, iterations, warmup)) {
+            Ok(timings) => results.extend(timings),
+            Err(e) => eprintln!("Skipping day {}, {}", day, e),
+        }
+    }
+
+    let report = BenchReport {
+        iterations,
+        warmup,
+        results,
+    };
+    let rendered = match format {
+        Format::Text => render_table(&report),
+        Format::Json => serde_json::to_string_pretty(&report)? + "\n",
+    };
+    match output {
+        Some(path) => {
+            fs::write(path, rendered).wrap_err_with(|| format!("Failed to write {}!", path))?
+        }
+        None => print!("{}", rendered),
+    }
+    Ok(())
+}
+
+fn render_table(report: &BenchReport) -> String {
+    let mut table = format!(
+        "{} iterations, {} warmup\nDay  Variant  Phase   {:>12}  {:>12}  {:>12}\n",
+        report.iterations, report.warmup, "Min", "Median", "P95"
+    );
+    for timings in &report.results {
+        table += &format!(
+            "{:>3}  {:7}  {:6}  {:>12}  {:>12}  {:>12}\n",
+            timings.day,
+            timings.variant,
+            timings.phase,
+            format!("{:.2?}", Duration::from_nanos(timings.min_ns)),
+            format!("{:.2?}", Duration::from_nanos(timings.median_ns)),
+            format!("{:.2?}", Duration::from_nanos(timings.p95_ns)),
+        );
+    }
+    table
+}
@@ -0,0 +1,99 @@
+use std::any::Any;
+use std::marker::PhantomData;
+use std::panic::{self, AssertUnwindSafe};
+
+use aoc_common::Solution;
+use eyre::{eyre, Result};
+
+pub const DAYS: u8 = 11;
+
+// Type-erased view of a day's `Solution` so the runner can pick one at runtime
+pub trait Solver {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    fn solve(&self, parsed: &dyn Any, part: u8) -> Result<String>;
+
+    fn warnings(&self, parsed: &dyn Any) -> Vec<String>;
+}
+
+struct Erased<S>(PhantomData<S>);
+
+impl<S: Solution> Solver for Erased<S>
+where
+    S::Parsed: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve(&self, parsed: &dyn Any, part: u8) -> Result<String> {
+        let parsed = downcast::<S>(parsed)?;
+        match part {
+            1 => Ok(S::part_1(parsed)?.to_string()),
+            2 => Ok(S::part_2(parsed)?.to_string()),
+            _ => Err(eyre!("Day {} has no part {}", S::DAY, part)),
+        }
+    }
+
+    fn warnings(&self, parsed: &dyn Any) -> Vec<String> {
+        match downcast::<S>(parsed) {
+            Ok(parsed) => S::warnings(parsed),
+            Err(e) => vec![e.to_string()],
+        }
+    }
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> Result<&S::Parsed>
+where
+    S::Parsed: 'static,
+{
+    parsed
+        .downcast_ref::<S::Parsed>()
+        .ok_or_else(|| eyre!("Parsed input doesn't belong to day {}", S::DAY))
+}
+
+fn erase<S: Solution + 'static>() -> Box<dyn Solver> {
+    Box::new(Erased::<S>(PhantomData))
+}
+
+pub fn solver(day: u8) -> Result<Box<dyn Solver>> {
+    match day {
+        1 => Ok(erase::<aoc_day1::Day1>()),
+        2 => Ok(erase::<aoc_day2::Day2>()),
+        3 => Ok(erase::<aoc_day3::Day3>()),
+        4 => Ok(erase::<aoc_day4::Day4>()),
+        5 => Ok(erase::<aoc_day5::Day5>()),
+        6 => Ok(erase::<aoc_day6::Day6>()),
+        7 => Ok(erase::<aoc_day7::Day7>()),
+        8 => Ok(erase::<aoc_day8::Day8>()),
+        9 => Ok(erase::<aoc_day9::Day9>()),
+        10 => Ok(erase::<aoc_day10::Day10>()),
+        11 => Ok(erase::<aoc_day11::Day11>()),
+        _ => Err(eyre!("No solution exists for day {}", day)),
+    }
+}
+
+// Run a single part of a day's solution against the given input, returning the answer as text
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String> {
+    let solver = solver(day)?;
+    let parsed = solver.parse(input)?;
+    solver.solve(parsed.as_ref(), part)
+}
+
+// Parsers report bad input as errors but solvers can still panic on input they didn't expect, turn
+//      those panics into errors so one bad day doesn't abort tooling that runs every day
+pub fn guarded<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    panic::set_hook(hook);
+
+    result.unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(eyre!("panicked: {}", message))
+    })
+}
@@ -0,0 +1,169 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+use aoc_common::normalize;
+use eyre::{eyre, Result, WrapErr};
+
+// Variant name of the personal puzzle input, every other variant is a `<variant>.txt` sample
+pub const PUZZLE_INPUT: &str = "input";
+
+// Year of the puzzles, inputs are stored per year so one store can be shared between years
+pub const YEAR: u16 = 2022;
+
+// Directory of a day's crate within the workspace
+pub fn day_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{day}"))
+}
+
+// Root of the local input store, `$AOC_CACHE_DIR` if set, otherwise `aoc` in the user's cache
+//      directory
+pub fn store_dir() -> Result<PathBuf> {
+    let var = |name| env::var_os(name).filter(|value| !value.is_empty());
+    if let Some(dir) = var("AOC_CACHE_DIR") {
+        return Ok(PathBuf::from(dir));
+    }
+    if let Some(dir) = var("XDG_CACHE_HOME") {
+        return Ok(PathBuf::from(dir).join("aoc"));
+    }
+    var("HOME")
+        .map(|home| PathBuf::from(home).join(".cache").join("aoc"))
+        .ok_or_else(|| eyre!("No cache directory found, set AOC_CACHE_DIR to choose one"))
+}
+
+// Directory holding every stored input variant of a day, `<store>/<year>/dayN`
+fn store_day_dir(day: u8) -> Result<PathBuf> {
+    Ok(store_dir()?
+        .join(YEAR.to_string())
+        .join(format!("day{day}")))
+}
+
+// Variants name a file in a day's directory, so they can't be used to reach outside of it
+fn check_variant(variant: &str) -> Result<()> {
+    if variant.is_empty() || variant.contains(['/', '\\']) || variant.contains("..") {
+        return Err(eyre!(
+            "Invalid input variant {:?}, variants can't be empty or contain path separators or '..'",
+            variant
+        ));
+    }
+    Ok(())
+}
+
+// Path of a named input variant in the store
+pub fn store_path(day: u8, variant: &str) -> Result<PathBuf> {
+    check_variant(variant)?;
+    Ok(store_day_dir(day)?.join(format!("{variant}.txt")))
+}
+
+// Path of a named input variant in the source tree, the puzzle input was kept next to its crate as
+//      `dayN/dayN.txt` before the store existed
+fn tree_path(day: u8, variant: &str) -> PathBuf {
+    if variant == PUZZLE_INPUT {
+        day_dir(day).join(format!("day{day}.txt"))
+    } else {
+        day_dir(day).join(format!("{variant}.txt"))
+    }
+}
+
+// Where a named input variant is read from, the store takes precedence over the source tree
+pub fn variant_path(day: u8, variant: &str) -> Result<PathBuf> {
+    check_variant(variant)?;
+    match store_path(day, variant) {
+        Ok(path) if path.exists() => Ok(path),
+        _ => Ok(tree_path(day, variant)),
+    }
+}
+
+// Every input variant present on disk for a day, in the source tree or the store
+pub fn variants(day: u8) -> Result<Vec<String>> {
+    let mut variants = Vec::new();
+    for entry in fs::read_dir(day_dir(day))? {
+        let path = entry?.path();
+        if !is_input_file(&path)? {
+            continue;
+        }
+        if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+            if stem == format!("day{day}") {
+                variants.push(PUZZLE_INPUT.to_string());
+            } else if stem.starts_with("sample") {
+                variants.push(stem.to_string());
+            }
+        }
+    }
+
+    if let Ok(Ok(entries)) = store_day_dir(day).map(fs::read_dir) {
+        for entry in entries {
+            let path = entry?.path();
+            if !is_input_file(&path)? {
+                continue;
+            }
+            if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+                variants.push(stem.to_string());
+            }
+        }
+    }
+
+    variants.sort();
+    variants.dedup();
+    Ok(variants)
+}
+
+// A `.txt` file with something in it, `aoc new` leaves an empty `sample.txt` to be filled in
+fn is_input_file(path: &Path) -> Result<bool> {
+    if path.extension().is_none_or(|ext| ext != "txt") {
+        return Ok(false);
+    }
+    Ok(fs::metadata(path)?.len() > 0)
+}
+
+// Read the input given on the command line, or the day's puzzle input if none was given
+pub fn read_input(day: u8, input: Option<&str>) -> Result<String> {
+    match input {
+        Some("-") => {
+            let mut buf = String::new();
+            io::stdin()
+                .read_to_string(&mut buf)
+                .wrap_err("Failed to read input from stdin!")?;
+            Ok(normalize(&buf).into_owned())
+        }
+        Some(path) => read_file(Path::new(path)),
+        None => {
+            let path = variant_path(day, PUZZLE_INPUT)?;
+            if !path.exists() {
+                return Err(eyre!(
+                    "No puzzle input for day {}, add one with `aoc input import {} <file>`",
+                    day,
+                    day
+                ));
+            }
+            read_file(&path)
+        }
+    }
+}
+
+// Copy an input into the store, normalized the same way it would be when read. `file` can be `-`
+//      to import from stdin.
+pub fn import(day: u8, file: &str, variant: &str, force: bool) -> Result<()> {
+    let input = read_input(day, Some(file))?;
+    let path = store_path(day, variant)?;
+    if path.exists() && !force {
+        return Err(eyre!(
+            "{} already exists, pass --force to replace it",
+            path.display()
+        ));
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .wrap_err_with(|| format!("Failed to create {}!", parent.display()))?;
+    }
+    fs::write(&path, input).wrap_err_with(|| format!("Failed to write {}!", path.display()))?;
+    println!("Imported day {} {} to {}", day, variant, path.display());
+    Ok(())
+}
+
+// Read a file with its line endings normalized, see `aoc_common::normalize`
+pub fn read_file(path: &Path) -> Result<String> {
+    aoc_common::read_input(path)
+}
@@ -0,0 +1,133 @@
+use clap::{Parser, Subcommand, ValueEnum};
+use eyre::Result;
+
+mod bench;
+mod days;
+mod inputs;
+mod run;
+mod scaffold;
+mod verify;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day's puzzle
+    Run {
+        /// Day to solve, every day with a puzzle input is solved if omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: Option<u8>,
+        /// Part to solve, both parts are solved if omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, or `-` to read from stdin. Defaults to the day's puzzle input
+        #[arg(short, long, requires = "day")]
+        input: Option<String>,
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Check every day's answers against those recorded in `dayN/answers.toml`
+    Verify {
+        /// Only verify a single day
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: Option<u8>,
+    },
+    /// Time the parse, part 1 and part 2 phases of each day separately
+    Bench {
+        /// Only benchmark a single day
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: Option<u8>,
+        /// Input variant to benchmark against, `input` or a sample name like `sample2`
+        #[arg(short, long, default_value = inputs::PUZZLE_INPUT)]
+        variant: String,
+        /// Number of timed runs of each phase
+        #[arg(short = 'n', long, default_value_t = 100, value_parser = clap::value_parser!(u64).range(1..))]
+        iterations: u64,
+        /// Number of untimed runs of each phase before timing starts
+        #[arg(short, long, default_value_t = 10)]
+        warmup: u64,
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Write the report to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Manage the local input store
+    Input {
+        #[command(subcommand)]
+        command: InputCommand,
+    },
+    /// Create the crate for the next day with a stubbed out solution and register it
+    New {
+        /// Day to create, must be the day after the last existing one
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+#[derive(Subcommand)]
+enum InputCommand {
+    /// Copy an input file into the store, `$AOC_CACHE_DIR` or `aoc` in the user's cache directory
+    Import {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: u8,
+        /// Input file, or `-` to read from stdin
+        file: String,
+        /// Variant to store the input as, `input` or a sample name like `sample2`
+        #[arg(short, long, default_value = inputs::PUZZLE_INPUT)]
+        variant: String,
+        /// Replace an input that's already in the store
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => run::run(day, part, input.as_deref(), format)?,
+        Command::Verify { day } => verify::verify(day)?,
+        Command::Bench {
+            day,
+            variant,
+            iterations,
+            warmup,
+            format,
+            output,
+        } => bench::bench(
+            day,
+            &variant,
+            iterations as usize,
+            warmup as usize,
+            format,
+            output.as_deref(),
+        )?,
+        Command::Input {
+            command:
+                InputCommand::Import {
+                    day,
+                    file,
+                    variant,
+                    force,
+                },
+        } => inputs::import(day, &file, &variant, force)?,
+        Command::New { day } => scaffold::new_day(day)?,
+    }
+    Ok(())
+}
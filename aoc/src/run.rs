@@ -0,0 +1,128 @@
+use std::time::Instant;
+
+use eyre::{eyre, Result};
+use serde::Serialize;
+
+use crate::{days, inputs, Format};
+
+// Outcome of solving one part of a day. A part that couldn't be solved has no answer and the
+//      reason is recorded as a warning.
+#[derive(Debug, Serialize)]
+struct Record {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    timings: Timings,
+    warnings: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Timings {
+    parse_ns: Option<u64>,
+    solve_ns: Option<u64>,
+}
+
+// Solve the requested parts of one day, or of every day with a puzzle input if no day is given
+pub fn run(day: Option<u8>, part: Option<u8>, input: Option<&str>, format: Format) -> Result<()> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut records = Vec::new();
+    match day {
+        Some(day) => {
+            let input = inputs::read_input(day, input)?;
+            records.extend(solve_day(day, &parts, &input)?);
+        }
+        None => {
+            for day in 1..=days::DAYS {
+                match inputs::read_input(day, None) {
+                    Ok(input) => records.extend(solve_day(day, &parts, &input)?),
+                    Err(_) => eprintln!("Skipping day {}, no puzzle input", day),
+                }
+            }
+        }
+    }
+
+    match format {
+        Format::Text => print_records(&records),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&records)?),
+    }
+
+    let failed = records.iter().filter(|r| r.answer.is_none()).count();
+    if failed > 0 {
+        return Err(eyre!(
+            "{} of {} parts couldn't be solved",
+            failed,
+            records.len()
+        ));
+    }
+    Ok(())
+}
+
+fn solve_day(day: u8, parts: &[u8], input: &str) -> Result<Vec<Record>> {
+    let solver = days::solver(day)?;
+    let now = Instant::now();
+    let parsed = days::guarded(|| solver.parse(input));
+    let parse_ns = Some(now.elapsed().as_nanos() as u64);
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            return Ok(parts
+                .iter()
+                .map(|&part| Record {
+                    day,
+                    part,
+                    answer: None,
+                    timings: Timings {
+                        parse_ns,
+                        solve_ns: None,
+                    },
+                    warnings: vec![format!("Failed to parse input: {}", e)],
+                })
+                .collect());
+        }
+    };
+
+    let warnings = days::guarded(|| Ok(solver.warnings(parsed.as_ref())))
+        .unwrap_or_else(|e| vec![format!("Failed to check input: {}", e)]);
+    let mut records = Vec::new();
+    for &part in parts {
+        let now = Instant::now();
+        let answer = days::guarded(|| solver.solve(parsed.as_ref(), part));
+        let solve_ns = Some(now.elapsed().as_nanos() as u64);
+
+        let mut warnings = warnings.clone();
+        if let Err(e) = &answer {
+            warnings.push(format!("Failed to solve: {}", e));
+        }
+        records.push(Record {
+            day,
+            part,
+            answer: answer.ok(),
+            timings: Timings { parse_ns, solve_ns },
+            warnings,
+        });
+    }
+    Ok(records)
+}
+
+fn print_records(records: &[Record]) {
+    for record in records {
+        match &record.answer {
+            Some(answer) if answer.contains('\n') => {
+                println!("Day {} part {}:\n{}", record.day, record.part, answer)
+            }
+            Some(answer) => println!("Day {} part {}: {}", record.day, record.part, answer),
+            None => println!("Day {} part {}: unsolved", record.day, record.part),
+        }
+        for warning in &record.warnings {
+            eprintln!(
+                "warning: day {} part {}: {}",
+                record.day, record.part, warning
+            );
+        }
+    }
+}
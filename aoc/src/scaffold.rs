@@ -0,0 +1,153 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use eyre::{eyre, Result, WrapErr};
+
+use crate::{days, inputs};
+
+// Files making up a new day's crate, relative to its directory. There's no `main.rs`, days are run
+//      through the runner so their input comes from the store.
+const TEMPLATES: [(&str, &str); 5] = [
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    (
+        "tests/sample.rs",
+        include_str!("../templates/sample.rs.tmpl"),
+    ),
+    (
+        "answers.toml",
+        include_str!("../templates/answers.toml.tmpl"),
+    ),
+    ("sample.txt", ""),
+];
+
+fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+// Create the `dayN` crate with a stubbed out `Solution`, an empty sample and a test skeleton, then
+//      register it with the workspace and the runner. Days have to be added in order since the
+//      runner expects every day up to `days::DAYS` to exist.
+pub fn new_day(day: u8) -> Result<()> {
+    let expected = days::DAYS + 1;
+    if day != expected {
+        return Err(eyre!(
+            "Days are added in order, the next day to create is day {}",
+            expected
+        ));
+    }
+    let dir = inputs::day_dir(day);
+    if dir.exists() {
+        return Err(eyre!("{} already exists!", dir.display()));
+    }
+
+    // Work out every registration edit up front so nothing is written if one of them fails
+    let member = format!("day{day}");
+    let runner_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let updates = [
+        edit_file(&workspace_dir().join("Cargo.toml"), |manifest| {
+            add_workspace_member(manifest, &member)
+        })?,
+        edit_file(&runner_dir.join("Cargo.toml"), |manifest| {
+            add_runner_dependency(manifest, day)
+        })?,
+        edit_file(&runner_dir.join("src/days.rs"), |source| {
+            add_solver(source, day)
+        })?,
+    ];
+
+    for (file, template) in TEMPLATES {
+        write_file(&dir.join(file), &render(template, day))?;
+    }
+    for (path, contents) in updates {
+        write_file(&path, &contents)?;
+    }
+
+    println!(
+        "Created {0}, add the puzzle's sample to {0}/sample.txt and its answers to {0}/answers.toml",
+        member
+    );
+    Ok(())
+}
+
+fn render(template: &str, day: u8) -> String {
+    template.replace("{{day}}", &day.to_string())
+}
+
+fn edit_file(path: &Path, edit: impl FnOnce(&str) -> Result<String>) -> Result<(PathBuf, String)> {
+    let contents =
+        fs::read_to_string(path).wrap_err_with(|| format!("Failed to read {}!", path.display()))?;
+    let edited =
+        edit(&contents).wrap_err_with(|| format!("Failed to update {}", path.display()))?;
+    Ok((path.to_path_buf(), edited))
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .wrap_err_with(|| format!("Failed to create {}!", parent.display()))?;
+    }
+    fs::write(path, contents).wrap_err_with(|| format!("Failed to write {}!", path.display()))
+}
+
+// Insert the member after the last day in the single line `members = [...]` list, keeping the
+//      runner last
+fn add_workspace_member(manifest: &str, member: &str) -> Result<String> {
+    let line = manifest
+        .lines()
+        .find(|line| line.starts_with("members = ["))
+        .ok_or_else(|| eyre!("No single line workspace members list found"))?;
+    let list = line
+        .trim_start_matches("members = [")
+        .strip_suffix(']')
+        .ok_or_else(|| eyre!("Workspace members list isn't on a single line"))?;
+
+    let mut members: Vec<&str> = list
+        .split(',')
+        .map(|m| m.trim().trim_matches('"'))
+        .filter(|m| !m.is_empty())
+        .collect();
+    let position = members
+        .iter()
+        .rposition(|m| m.starts_with("day"))
+        .map_or(members.len(), |i| i + 1);
+    members.insert(position, member);
+
+    let list: Vec<String> = members.iter().map(|m| format!("\"{m}\"")).collect();
+    let updated = format!("members = [{}]", list.join(", "));
+    Ok(manifest.replacen(line, &updated, 1))
+}
+
+fn add_runner_dependency(manifest: &str, day: u8) -> Result<String> {
+    let previous = format!("aoc-day{} = {{ path = \"../day{}\" }}\n", day - 1, day - 1);
+    let dependency = format!("aoc-day{day} = {{ path = \"../day{day}\" }}\n");
+    insert_after(manifest, &previous, &dependency)
+}
+
+fn add_solver(source: &str, day: u8) -> Result<String> {
+    let previous = format!(
+        "        {0} => Ok(erase::<aoc_day{0}::Day{0}>()),\n",
+        day - 1
+    );
+    let solver = format!("        {day} => Ok(erase::<aoc_day{day}::Day{day}>()),\n");
+    let source = insert_after(source, &previous, &solver)?;
+
+    let count = format!("pub const DAYS: u8 = {};", day - 1);
+    if !source.contains(&count) {
+        return Err(eyre!("Couldn't find `{}`", count));
+    }
+    Ok(source.replacen(&count, &format!("pub const DAYS: u8 = {day};"), 1))
+}
+
+fn insert_after(contents: &str, anchor: &str, insert: &str) -> Result<String> {
+    let end = contents
+        .find(anchor)
+        .map(|start| start + anchor.len())
+        .ok_or_else(|| eyre!("Couldn't find `{}`", anchor.trim()))?;
+    Ok(format!(
+        "{}{}{}",
+        &contents[..end],
+        insert,
+        &contents[end..]
+    ))
+}
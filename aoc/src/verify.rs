@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use eyre::{eyre, Result, WrapErr};
+use serde::Deserialize;
+
+use crate::{days, inputs};
+
+// Known answers for a single input variant, either part may be unknown
+#[derive(Debug, Default, Deserialize)]
+struct Answers {
+    part_1: Option<String>,
+    part_2: Option<String>,
+}
+
+impl Answers {
+    fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            _ => self.part_2.as_deref(),
+        }
+    }
+}
+
+// Answers recorded in `dayN/answers.toml`, keyed by input variant
+type Registry = BTreeMap<String, Answers>;
+
+fn load_answers(day: u8) -> Result<Registry> {
+    let path = inputs::day_dir(day).join("answers.toml");
+    if !path.exists() {
+        return Ok(Registry::new());
+    }
+    let contents = inputs::read_file(&path)?;
+    toml::from_str(&contents).wrap_err_with(|| format!("Failed to parse {}!", path.display()))
+}
+
+#[derive(Debug, PartialEq)]
+enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+        };
+        f.pad(status)
+    }
+}
+
+struct Check {
+    day: u8,
+    variant: String,
+    part: u8,
+    status: Status,
+    expected: Option<String>,
+    actual: Option<String>,
+    note: String,
+}
+
+// Run every day's solver against each of its input variants and compare with the known answers.
+//      Fails if any answer doesn't match or the solver errors where an answer is known, unknown
+//      answers are only reported as missing.
+pub fn verify(day: Option<u8>) -> Result<()> {
+    let days: Vec<u8> = match day {
+        Some(day) => vec![day],
+        None => (1..=days::DAYS).collect(),
+    };
+
+    let mut checks = Vec::new();
+    for day in days {
+        checks.extend(check_day(day)?);
+    }
+
+    print_table(&checks);
+
+    let failed = checks.iter().filter(|c| c.status == Status::Fail).count();
+    if failed > 0 {
+        return Err(eyre!("{} of {} checks failed", failed, checks.len()));
+    }
+    Ok(())
+}
+
+fn check_day(day: u8) -> Result<Vec<Check>> {
+    let registry = load_answers(day)?;
+    let mut variants = inputs::variants(day)?;
+    for variant in registry.keys() {
+        if !variants.contains(variant) {
+            variants.push(variant.clone());
+        }
+    }
+
+    let mut checks = Vec::new();
+    for variant in variants {
+        let answers = registry.get(&variant);
+        let input = inputs::variant_path(day, &variant)
+            .and_then(|path| inputs::read_file(&path))
+            .ok();
+        for part in 1..=2 {
+            let expected = answers.and_then(|a| a.get(part)).map(String::from);
+            checks.push(match &input {
+                Some(input) => check_part(day, &variant, part, input, expected),
+                None => Check {
+                    day,
+                    variant: variant.clone(),
+                    part,
+                    status: Status::Missing,
+                    expected,
+                    actual: None,
+                    note: "no input file".to_string(),
+                },
+            });
+        }
+    }
+    Ok(checks)
+}
+
+fn check_part(day: u8, variant: &str, part: u8, input: &str, expected: Option<String>) -> Check {
+    let actual = days::guarded(|| days::solve(day, part, input)).map_err(|e| e.to_string());
+
+    let (status, note) = match (&actual, &expected) {
+        // A day that isn't solved yet has nothing recorded to fail against
+        (Err(e), None) => (Status::Missing, format!("unsolved, {}", e)),
+        (Err(e), Some(_)) => (Status::Fail, e.clone()),
+        (Ok(actual), None) => (Status::Missing, summarize(actual)),
+        (Ok(actual), Some(expected)) if actual.trim_end() == expected.trim_end() => {
+            (Status::Pass, summarize(actual))
+        }
+        (Ok(actual), Some(expected)) => (
+            Status::Fail,
+            format!(
+                "expected {}, got {}",
+                summarize(expected),
+                summarize(actual)
+            ),
+        ),
+    };
+
+    Check {
+        day,
+        variant: variant.to_string(),
+        part,
+        status,
+        expected,
+        actual: actual.ok(),
+        note,
+    }
+}
+
+// Multi-line answers (like the day 10 CRT) don't fit in a table cell
+fn summarize(answer: &str) -> String {
+    let lines = answer.trim_end().lines().count();
+    if lines > 1 {
+        format!("<{} lines>", lines)
+    } else {
+        answer.to_string()
+    }
+}
+
+fn print_table(checks: &[Check]) {
+    let width = checks
+        .iter()
+        .map(|c| c.variant.len())
+        .max()
+        .unwrap_or(0)
+        .max("Variant".len());
+    println!("Day  {:width$}  Part  Status   Notes", "Variant");
+    for check in checks {
+        println!(
+            "{:>3}  {:width$}  {:>4}  {:7}  {}",
+            check.day, check.variant, check.part, check.status, check.note
+        );
+    }
+
+    // Show the full answers for multi-line mismatches underneath the table
+    for check in checks.iter().filter(|c| c.status == Status::Fail) {
+        if let (Some(expected), Some(actual)) = (&check.expected, &check.actual) {
+            if expected.contains('\n') || actual.contains('\n') {
+                println!(
+                    "\nDay {} {} part {}\nExpected:\n{}\nGot:\n{}",
+                    check.day,
+                    check.variant,
+                    check.part,
+                    expected.trim_end(),
+                    actual.trim_end()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+// An input that couldn't be parsed, located by 1-based line and column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(
+        day: u8,
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            day,
+            line,
+            column,
+            text: text.into(),
+            reason: reason.into(),
+        }
+    }
+
+    // Error about a whole line of the input
+    pub fn line(day: u8, line: usize, text: &str, reason: impl Into<String>) -> Self {
+        ParseError::new(day, line, 1, text, reason)
+    }
+
+    // Error about `token`, which should be a slice of `text` (the contents of the given line).
+    //      The column is worked out from where the token sits within the line.
+    pub fn at(day: u8, line: usize, text: &str, token: &str, reason: impl Into<String>) -> Self {
+        ParseError::new(day, line, column_of(text, token), token, reason)
+    }
+}
+
+fn column_of(text: &str, token: &str) -> usize {
+    let start = text.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize).wrapping_sub(start);
+    let offset = if offset <= text.len() && text.is_char_boundary(offset) {
+        offset
+    } else {
+        text.find(token).unwrap_or(0)
+    };
+    text[..offset].chars().count() + 1
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {} input, line {} column {}: {} ({:?})",
+            self.day, self.line, self.column, self.reason, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
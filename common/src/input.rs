@@ -0,0 +1,31 @@
+use std::borrow::Cow;
+use std::fs;
+use std::path::Path;
+
+use eyre::{Result, WrapErr};
+
+const BOM: char = '\u{feff}';
+
+// Bring an input to the canonical form every parser expects: no byte order mark, LF line endings
+//      and exactly one newline at the end (none for an empty input). Already canonical inputs are
+//      borrowed rather than copied.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix(BOM).unwrap_or(input);
+    let content = input.trim_end_matches(['\r', '\n']);
+    let canonical_end = if content.is_empty() { "" } else { "\n" };
+    if !content.contains('\r') && &input[content.len()..] == canonical_end {
+        return Cow::Borrowed(input);
+    }
+
+    let mut normalized = content.replace("\r\n", "\n").replace('\r', "\n");
+    normalized.push_str(canonical_end);
+    Cow::Owned(normalized)
+}
+
+// Read an input file and normalize it, so parsers don't care how it was saved
+pub fn read_input(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    let input = fs::read_to_string(path)
+        .wrap_err_with(|| format!("Failed to read input file {}!", path.display()))?;
+    Ok(normalize(&input).into_owned())
+}
@@ -0,0 +1,33 @@
+use std::fmt::Display;
+
+use eyre::Result;
+
+mod error;
+mod input;
+mod rng;
+
+pub use error::ParseError;
+pub use input::{normalize, read_input};
+pub use rng::Rng;
+
+// Common interface implemented by every day's solution so tooling can treat them uniformly.
+//      The input is parsed once and both parts are solved from the same parsed value.
+pub trait Solution {
+    const DAY: u8;
+
+    type Parsed;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn part_1(parsed: &Self::Parsed) -> Result<Self::Answer1>;
+
+    fn part_2(parsed: &Self::Parsed) -> Result<Self::Answer2>;
+
+    // Problems noticed in the parsed input that don't stop it from being solved, but likely make
+    //      the answers meaningless
+    fn warnings(_parsed: &Self::Parsed) -> Vec<String> {
+        Vec::new()
+    }
+}
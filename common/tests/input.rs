@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+
+use aoc_common::normalize;
+
+#[test]
+fn canonical_input_is_borrowed() {
+    assert!(matches!(normalize("1\n\n2\n"), Cow::Borrowed("1\n\n2\n")));
+}
+
+#[test]
+fn crlf_becomes_lf() {
+    assert_eq!(normalize("1\r\n\r\n2\r\n"), "1\n\n2\n");
+}
+
+#[test]
+fn trailing_newlines_collapse_to_one() {
+    assert_eq!(normalize("1\n2"), "1\n2\n");
+    assert_eq!(normalize("1\n2\n\n\n"), "1\n2\n");
+    assert_eq!(normalize("1\r\n2\r\n\r\n"), "1\n2\n");
+}
+
+#[test]
+fn byte_order_mark_is_dropped() {
+    assert_eq!(normalize("\u{feff}1\r\n2\r\n"), "1\n2\n");
+}
+
+#[test]
+fn empty_input_stays_empty() {
+    assert_eq!(normalize(""), "");
+    assert_eq!(normalize("\r\n\n"), "");
+}
+
+#[test]
+fn leading_whitespace_is_kept() {
+    // Day 5's stack drawing starts with spaces where a stack is shorter
+    assert_eq!(normalize("    [D]\r\n[N] [C]\r\n"), "    [D]\n[N] [C]\n");
+}
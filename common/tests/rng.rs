@@ -0,0 +1,20 @@
+use aoc_common::Rng;
+
+#[test]
+fn same_seed_same_sequence() {
+    let (mut a, mut b) = (Rng::new(2022), Rng::new(2022));
+    for _ in 0..100 {
+        assert_eq!(a.next_u64(), b.next_u64());
+    }
+    assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+}
+
+#[test]
+fn below_stays_in_bounds() {
+    let mut rng = Rng::new(7);
+    let mut seen = [false; 5];
+    for _ in 0..1000 {
+        seen[rng.below(5)] = true;
+    }
+    assert!(seen.iter().all(|&seen| seen));
+}
@@ -0,0 +1,116 @@
+use aoc_common::ParseError;
+
+use crate::DAY;
+
+// The calories of every item carried by each elf, with elves in the order they appear in the
+//      input. Elves are numbered from 1 like in `parse_into_elves`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Inventory {
+    elves: Vec<Vec<u64>>,
+}
+
+// An elf's place in the ranking. Elves carrying the same total share a rank and the next rank is
+//      skipped, so totals of 10, 8, 8, 5 rank 1, 2, 2, 4.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rank {
+    pub rank: usize,
+    pub elf: usize,
+    pub total: u64,
+}
+
+impl Inventory {
+    pub fn new(elves: Vec<Vec<u64>>) -> Self {
+        Inventory { elves }
+    }
+
+    // Elves are separated by a blank line, so consecutive blank lines mean an elf carrying nothing
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let input = aoc_common::normalize(input);
+        if input.is_empty() {
+            return Ok(Inventory::default());
+        }
+
+        let mut elves = vec![Vec::new()];
+        for (i, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                elves.push(Vec::new());
+                continue;
+            }
+            let calories = line
+                .parse()
+                .map_err(|e| ParseError::line(DAY, i + 1, line, format!("Invalid calories, {}", e)))?;
+            if let Some(items) = elves.last_mut() {
+                items.push(calories);
+            }
+        }
+        Ok(Inventory { elves })
+    }
+
+    pub fn len(&self) -> usize {
+        self.elves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elves.is_empty()
+    }
+
+    // Every elf's items, the first elf's are at index 0
+    pub fn elves(&self) -> &[Vec<u64>] {
+        &self.elves
+    }
+
+    pub fn items(&self, elf: usize) -> Option<&[u64]> {
+        self.elves.get(elf.checked_sub(1)?).map(Vec::as_slice)
+    }
+
+    pub fn total(&self, elf: usize) -> Option<u64> {
+        self.items(elf).map(sum)
+    }
+
+    // Every elf's total calories, the first elf's is at index 0
+    pub fn totals(&self) -> Vec<u64> {
+        self.elves.iter().map(|items| sum(items)).collect()
+    }
+
+    // The `n` elves carrying the most as (elf, total), most first. Elves carrying the same total
+    //      are ordered by number, so ties at the cut off favour the earlier elf.
+    pub fn top(&self, n: usize) -> Vec<(usize, u64)> {
+        let mut elves: Vec<(usize, u64)> = self
+            .totals()
+            .into_iter()
+            .enumerate()
+            .map(|(i, total)| (i + 1, total))
+            .collect();
+        elves.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        elves.truncate(n);
+        elves
+    }
+
+    // Total calories carried by the `n` elves carrying the most
+    pub fn top_total(&self, n: usize) -> u64 {
+        self.top(n).iter().map(|(_, total)| total).sum()
+    }
+
+    // Every elf ranked by total calories, most first
+    pub fn ranking(&self) -> Vec<Rank> {
+        let mut ranking: Vec<Rank> = Vec::with_capacity(self.len());
+        for (position, (elf, total)) in self.top(self.len()).into_iter().enumerate() {
+            let rank = match ranking.last() {
+                Some(previous) if previous.total == total => previous.rank,
+                _ => position + 1,
+            };
+            ranking.push(Rank { rank, elf, total });
+        }
+        ranking
+    }
+
+    // Rank of a single elf, see `ranking`
+    pub fn rank_of(&self, elf: usize) -> Option<usize> {
+        let total = self.total(elf)?;
+        Some(self.totals().iter().filter(|&&other| other > total).count() + 1)
+    }
+}
+
+fn sum(items: &[u64]) -> u64 {
+    items.iter().sum()
+}
@@ -0,0 +1,65 @@
+use aoc_common::{ParseError, Solution};
+use eyre::Result;
+use std::collections::HashMap;
+
+mod inventory;
+mod rebalance;
+mod stats;
+mod streaming;
+
+pub use inventory::{Inventory, Rank};
+pub use rebalance::{Move, Rebalance, Strategy, EXACT_ITEM_LIMIT, EXACT_NODE_BUDGET};
+pub use stats::{Bucket, Stats, HISTOGRAM_BUCKETS};
+pub use streaming::{stream_top_k, stream_top_total};
+
+const DAY: u8 = 1;
+
+// Sum the calories carried by each elf. Elves are separated by blank lines and numbered from 1.
+//      Items and sums are u64 as large generated inventories can overflow a u32, the same as
+//      `Inventory` and `stream_top_k`.
+pub fn parse_into_elves(input: String) -> Result<HashMap<u32, u64>, ParseError> {
+    let mut elf_count = 1;
+    let mut elves = HashMap::new();
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            elf_count += 1;
+            continue;
+        }
+        let calories: u64 = line
+            .parse()
+            .map_err(|e| ParseError::line(DAY, i + 1, line, format!("Invalid calories, {}", e)))?;
+        let elf_calories = elves.entry(elf_count).or_insert(0);
+        *elf_calories += calories;
+    }
+
+    Ok(elves)
+}
+
+// Sum the calories carried by the `n` elves carrying the most
+pub fn top_elves_total(elves: &HashMap<u32, u64>, n: usize) -> u64 {
+    let mut totals: Vec<&u64> = elves.values().collect();
+    totals.sort_by(|a, b| b.cmp(a));
+    totals.into_iter().take(n).sum()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = DAY;
+
+    type Parsed = Inventory;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Inventory::parse(input)
+    }
+
+    fn part_1(inventory: &Self::Parsed) -> Result<Self::Answer1> {
+        Ok(inventory.top_total(1))
+    }
+
+    fn part_2(inventory: &Self::Parsed) -> Result<Self::Answer2> {
+        Ok(inventory.top_total(3))
+    }
+}
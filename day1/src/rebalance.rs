@@ -0,0 +1,320 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+use crate::Inventory;
+
+// Inventories with at most this many items are rebalanced exactly by `Strategy::Auto`
+pub const EXACT_ITEM_LIMIT: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    // Branch and bound search for the smallest possible maximum, exponential in the number of items.
+    //      Gives up after `EXACT_NODE_BUDGET` nodes with the best split found, not proven optimal.
+    Exact,
+    // Longest processing time first, hand the largest remaining item to the least loaded elf. At
+    //      most 4/3 of the best possible maximum.
+    Greedy,
+    // Exact for inventories of up to `EXACT_ITEM_LIMIT` items, greedy for anything larger
+    Auto,
+}
+
+// A single item handed from one elf to another
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub calories: u64,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rebalance {
+    // The items each elf carries once the moves are made
+    pub inventory: Inventory,
+    pub max_total: u64,
+    // Whether `max_total` is known to be the smallest possible
+    pub optimal: bool,
+    pub moves: Vec<Move>,
+}
+
+impl Inventory {
+    // Redistribute the items among the same elves so the most any one elf carries is as small as
+    //      the strategy can get it (multiway number partitioning), along with the moves to get there
+    pub fn rebalance(&self, strategy: Strategy) -> Rebalance {
+        let mut items: Vec<u64> = self.elves().iter().flatten().copied().collect();
+        items.sort_unstable_by(|a, b| b.cmp(a));
+        let exact = match strategy {
+            Strategy::Exact => true,
+            Strategy::Greedy => false,
+            Strategy::Auto => items.len() <= EXACT_ITEM_LIMIT,
+        };
+
+        let elves = self.len();
+        let greedy = longest_first(&items, elves);
+        let (bins, optimal) = if exact {
+            BranchAndBound::solve(&items, elves, greedy)
+        } else {
+            let optimal = max_total(&greedy) == lower_bound(&items, elves);
+            (greedy, optimal)
+        };
+
+        // Elves already close to the target get there faster by giving away a few items than by
+        //      taking on a whole new load
+        let target = max_total(&bins);
+        let bins = match_to_elves(self.elves(), bins);
+        let moves = moves_between(self.elves(), &bins);
+        let (bins, moves) = match move_off_heaviest(self.elves(), target, moves.len()) {
+            Some(shorter) => shorter,
+            None => (bins, moves),
+        };
+        Rebalance {
+            max_total: max_total(&bins),
+            inventory: Inventory::new(bins),
+            optimal,
+            moves,
+        }
+    }
+}
+
+fn total(items: &[u64]) -> u64 {
+    items.iter().sum()
+}
+
+fn max_total(bins: &[Vec<u64>]) -> u64 {
+    bins.iter().map(|bin| total(bin)).max().unwrap_or(0)
+}
+
+// No split can do better than sharing the calories perfectly evenly, or than the largest item
+fn lower_bound(items: &[u64], bins: usize) -> u64 {
+    if bins == 0 {
+        return 0;
+    }
+    let largest = items.iter().max().copied().unwrap_or(0);
+    total(items).div_ceil(bins as u64).max(largest)
+}
+
+// `items` must be sorted largest first
+fn longest_first(items: &[u64], bins: usize) -> Vec<Vec<u64>> {
+    let mut assigned = vec![Vec::new(); bins];
+    let mut loads: BinaryHeap<Reverse<(u64, usize)>> = (0..bins).map(|i| Reverse((0, i))).collect();
+    for &calories in items {
+        let Some(Reverse((load, bin))) = loads.pop() else {
+            break;
+        };
+        assigned[bin].push(calories);
+        loads.push(Reverse((load + calories, bin)));
+    }
+    assigned
+}
+
+// Search nodes `Strategy::Exact` visits before settling for the best split found so far
+pub const EXACT_NODE_BUDGET: usize = 2_000_000;
+
+struct BranchAndBound<'a> {
+    items: &'a [u64],
+    // `remaining[i]` is the total of items `i` onwards
+    remaining: Vec<u64>,
+    lower_bound: u64,
+    loads: Vec<u64>,
+    assignment: Vec<usize>,
+    best: u64,
+    best_assignment: Vec<usize>,
+    nodes: usize,
+}
+
+impl<'a> BranchAndBound<'a> {
+    // Start from the greedy split so only strictly better splits need exploring. Also returns
+    //      whether the split is proven optimal, which it isn't if the search ran out of budget.
+    fn solve(items: &'a [u64], bins: usize, greedy: Vec<Vec<u64>>) -> (Vec<Vec<u64>>, bool) {
+        let mut remaining = vec![0; items.len() + 1];
+        for (i, &calories) in items.iter().enumerate().rev() {
+            remaining[i] = remaining[i + 1] + calories;
+        }
+        let mut search = BranchAndBound {
+            items,
+            remaining,
+            lower_bound: lower_bound(items, bins),
+            loads: vec![0; bins],
+            assignment: vec![0; items.len()],
+            best: max_total(&greedy),
+            best_assignment: Vec::new(),
+            nodes: 0,
+        };
+        if bins == 0 || search.best == search.lower_bound {
+            return (greedy, true);
+        }
+        search.branch(0, 0);
+        let optimal = search.best == search.lower_bound || search.nodes < EXACT_NODE_BUDGET;
+        if search.best_assignment.is_empty() {
+            return (greedy, optimal);
+        }
+
+        let mut assigned = vec![Vec::new(); bins];
+        for (&calories, &bin) in items.iter().zip(&search.best_assignment) {
+            assigned[bin].push(calories);
+        }
+        (assigned, optimal)
+    }
+
+    // Place item `index` in each bin it could go in, `current` is the largest load so far
+    fn branch(&mut self, index: usize, current: u64) {
+        if self.best == self.lower_bound || self.nodes >= EXACT_NODE_BUDGET {
+            return;
+        }
+        self.nodes += 1;
+        if index == self.items.len() {
+            if current < self.best {
+                self.best = current;
+                self.best_assignment = self.assignment.clone();
+            }
+            return;
+        }
+        // To beat the best split, the items left have to fit in the room below it
+        let room: u64 = self
+            .loads
+            .iter()
+            .map(|&load| (self.best - 1).saturating_sub(load))
+            .sum();
+        if room < self.remaining[index] {
+            return;
+        }
+
+        let calories = self.items[index];
+        let mut tried: Vec<u64> = Vec::new();
+        for bin in 0..self.loads.len() {
+            let load = self.loads[bin] + calories;
+            // Bins with the same load lead to the same splits, so only try the first of them
+            if load >= self.best || tried.contains(&self.loads[bin]) {
+                continue;
+            }
+            tried.push(self.loads[bin]);
+
+            self.loads[bin] = load;
+            self.assignment[index] = bin;
+            self.branch(index + 1, current.max(load));
+            self.loads[bin] -= calories;
+        }
+    }
+}
+
+// Number of items two elves have in common
+fn overlap(a: &[u64], b: &[u64]) -> usize {
+    let mut counts: HashMap<u64, usize> = HashMap::new();
+    for &calories in a {
+        *counts.entry(calories).or_default() += 1;
+    }
+    b.iter()
+        .filter(|calories| match counts.get_mut(calories) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        })
+        .count()
+}
+
+// Any elf can take any of the new loads, give each elf the load it shares the most items with so
+//      few items have to move. Matched greedily, so the move list is short but not always the
+//      shortest.
+fn match_to_elves(elves: &[Vec<u64>], bins: Vec<Vec<u64>>) -> Vec<Vec<u64>> {
+    let mut pairs: Vec<(usize, usize, usize)> = Vec::new();
+    for (bin, items) in bins.iter().enumerate() {
+        for (elf, carried) in elves.iter().enumerate() {
+            let shared = overlap(carried, items);
+            if shared > 0 {
+                pairs.push((shared, bin, elf));
+            }
+        }
+    }
+    pairs.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)));
+
+    let mut matched: Vec<Option<Vec<u64>>> = vec![None; elves.len()];
+    let mut bins: Vec<Option<Vec<u64>>> = bins.into_iter().map(Some).collect();
+    for (_, bin, elf) in pairs {
+        if matched[elf].is_none() && bins[bin].is_some() {
+            matched[elf] = bins[bin].take();
+        }
+    }
+    let mut unmatched = bins.into_iter().flatten();
+    matched
+        .into_iter()
+        .map(|bin| bin.or_else(|| unmatched.next()).unwrap_or_default())
+        .collect()
+}
+
+// Starting from the current loads, repeatedly move an item from the heaviest elf to the lightest
+//      one until no elf carries more than `target`. `None` if that takes `limit` moves or more, or
+//      gets stuck before reaching it.
+fn move_off_heaviest(
+    elves: &[Vec<u64>],
+    target: u64,
+    limit: usize,
+) -> Option<(Vec<Vec<u64>>, Vec<Move>)> {
+    let mut bins = elves.to_vec();
+    let mut loads: Vec<u64> = bins.iter().map(|bin| total(bin)).collect();
+    let mut moves = Vec::new();
+    loop {
+        let (heaviest, &load) = loads.iter().enumerate().max_by_key(|&(_, &load)| load)?;
+        if load <= target {
+            return Some((bins, moves));
+        }
+        if moves.len() >= limit {
+            return None;
+        }
+        let (lightest, &light) = loads.iter().enumerate().min_by_key(|&(_, &load)| load)?;
+
+        // Only moves that leave both elves lighter than the heaviest was make progress. The
+        //      smallest item that gets the heaviest elf within the target is enough, failing that
+        //      the one that brings it down the most.
+        let (position, _) = bins[heaviest]
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|&(_, calories)| light + calories < load)
+            .min_by_key(|&(_, calories)| {
+                let after = (load - calories).max(light + calories);
+                if after <= target {
+                    (false, calories)
+                } else {
+                    (true, after)
+                }
+            })?;
+        let calories = bins[heaviest].swap_remove(position);
+        bins[lightest].push(calories);
+        loads[heaviest] -= calories;
+        loads[lightest] += calories;
+        moves.push(Move {
+            calories,
+            from: heaviest + 1,
+            to: lightest + 1,
+        });
+    }
+}
+
+// Items leaving each elf are paired up with items of the same calories arriving at another
+fn moves_between(before: &[Vec<u64>], after: &[Vec<u64>]) -> Vec<Move> {
+    let mut leaving: HashMap<u64, VecDeque<usize>> = HashMap::new();
+    let mut arriving: Vec<(u64, usize)> = Vec::new();
+    for (elf, (old, new)) in before.iter().zip(after).enumerate() {
+        let mut old = old.clone();
+        for &calories in new {
+            match old.iter().position(|&kept| kept == calories) {
+                Some(position) => {
+                    old.swap_remove(position);
+                }
+                None => arriving.push((calories, elf + 1)),
+            }
+        }
+        for calories in old {
+            leaving.entry(calories).or_default().push_back(elf + 1);
+        }
+    }
+
+    arriving
+        .into_iter()
+        .filter_map(|(calories, to)| {
+            let from = leaving.get_mut(&calories)?.pop_front()?;
+            Some(Move { calories, from, to })
+        })
+        .collect()
+}
@@ -0,0 +1,170 @@
+use std::fmt::{Display, Formatter};
+
+use crate::Inventory;
+
+// Number of buckets in the histogram of `Stats`
+pub const HISTOGRAM_BUCKETS: usize = 10;
+
+// Width of the longest histogram bar in the text report
+const BAR_WIDTH: usize = 40;
+
+// Summary of an inventory. Averages are over every elf's total calories and are 0 for an inventory
+//      with no elves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub elves: usize,
+    pub items: usize,
+    pub total: u64,
+    pub mean: f64,
+    pub median: f64,
+    pub stddev: f64,
+    // (elf, calories) of the single largest item, the earliest elf's if there's a tie
+    pub largest_item: Option<(usize, u64)>,
+    pub empty_elves: Vec<usize>,
+    pub histogram: Vec<Bucket>,
+}
+
+// Number of elves carrying a total within `low..=high` calories
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub low: u64,
+    pub high: u64,
+    pub count: usize,
+}
+
+impl Inventory {
+    pub fn stats(&self) -> Stats {
+        let mut totals = self.totals();
+        totals.sort_unstable();
+
+        // Summed as u128 so even an inventory of elves carrying u64::MAX can't overflow
+        let total: u128 = totals.iter().map(|&t| u128::from(t)).sum();
+        let (mean, median, stddev) = if totals.is_empty() {
+            (0.0, 0.0, 0.0)
+        } else {
+            let mean = total as f64 / totals.len() as f64;
+            let middle = totals.len() / 2;
+            let median = if totals.len().is_multiple_of(2) {
+                (totals[middle - 1] as f64 + totals[middle] as f64) / 2.0
+            } else {
+                totals[middle] as f64
+            };
+            let variance = totals
+                .iter()
+                .map(|&t| (t as f64 - mean).powi(2))
+                .sum::<f64>()
+                / totals.len() as f64;
+            (mean, median, variance.sqrt())
+        };
+
+        let mut largest_item: Option<(usize, u64)> = None;
+        for (i, items) in self.elves().iter().enumerate() {
+            for &calories in items {
+                if largest_item.is_none_or(|(_, largest)| calories > largest) {
+                    largest_item = Some((i + 1, calories));
+                }
+            }
+        }
+
+        Stats {
+            elves: self.len(),
+            items: self.elves().iter().map(Vec::len).sum(),
+            total: u64::try_from(total).unwrap_or(u64::MAX),
+            mean,
+            median,
+            stddev,
+            largest_item,
+            empty_elves: self.empty_elves(),
+            histogram: histogram(&totals, HISTOGRAM_BUCKETS),
+        }
+    }
+
+    // Elves carrying no items, from consecutive blank lines in the input
+    pub fn empty_elves(&self) -> Vec<usize> {
+        self.elves()
+            .iter()
+            .enumerate()
+            .filter(|(_, items)| items.is_empty())
+            .map(|(i, _)| i + 1)
+            .collect()
+    }
+
+    // Count elves into `buckets` equally wide ranges of total calories spanning the smallest to
+    //      the largest total. There are fewer buckets when the totals span fewer calories.
+    pub fn histogram(&self, buckets: usize) -> Vec<Bucket> {
+        let mut totals = self.totals();
+        totals.sort_unstable();
+        histogram(&totals, buckets)
+    }
+}
+
+fn histogram(sorted_totals: &[u64], buckets: usize) -> Vec<Bucket> {
+    let (Some(&min), Some(&max)) = (sorted_totals.first(), sorted_totals.last()) else {
+        return Vec::new();
+    };
+    if buckets == 0 {
+        return Vec::new();
+    }
+    let span = u128::from(max - min) + 1;
+    let width = span.div_ceil(buckets as u128);
+    let buckets = span.div_ceil(width) as usize;
+
+    let mut histogram: Vec<Bucket> = (0..buckets)
+        .map(|i| {
+            let low = u128::from(min) + i as u128 * width;
+            let high = (low + width - 1).min(u128::from(max));
+            Bucket {
+                low: low as u64,
+                high: high as u64,
+                count: 0,
+            }
+        })
+        .collect();
+    for &total in sorted_totals {
+        let index = (u128::from(total - min) / width) as usize;
+        histogram[index].count += 1;
+    }
+    histogram
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Elves: {}", self.elves)?;
+        writeln!(f, "Items: {}", self.items)?;
+        writeln!(f, "Total calories: {}", self.total)?;
+        writeln!(
+            f,
+            "Calories per elf: mean {:.1}, median {:.1}, stddev {:.1}",
+            self.mean, self.median, self.stddev
+        )?;
+        match self.largest_item {
+            Some((elf, calories)) => {
+                writeln!(f, "Largest item: {} calories, carried by elf {}", calories, elf)?
+            }
+            None => writeln!(f, "Largest item: none")?,
+        }
+        if self.empty_elves.is_empty() {
+            writeln!(f, "Elves carrying no items: none")?;
+        } else {
+            let elves: Vec<String> = self.empty_elves.iter().map(|e| e.to_string()).collect();
+            writeln!(f, "Elves carrying no items: {}", elves.join(", "))?;
+        }
+
+        let largest = self.histogram.iter().map(|b| b.count).max().unwrap_or(0);
+        let low_width = self.histogram.iter().map(|b| b.low.to_string().len()).max().unwrap_or(0);
+        let high_width = self.histogram.iter().map(|b| b.high.to_string().len()).max().unwrap_or(0);
+        writeln!(f, "Histogram of calories per elf:")?;
+        for bucket in &self.histogram {
+            let bar = (bucket.count * BAR_WIDTH).div_ceil(largest.max(1));
+            writeln!(
+                f,
+                "{:>low_width$} - {:>high_width$} | {:BAR_WIDTH$} {}",
+                bucket.low,
+                bucket.high,
+                "#".repeat(bar),
+                bucket.count
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+use aoc_common::ParseError;
+use eyre::{Result, WrapErr};
+
+use crate::DAY;
+
+// Find the `k` elves carrying the most as (elf, total), most first, reading the inventory a line at
+//      a time. Only the best `k` elves seen so far are kept, in a min-heap so the worst of them is
+//      the one to drop, so memory doesn't grow with the size of the input. Agrees with
+//      `Inventory::top`, including which elf wins a tie at the cut off.
+pub fn stream_top_k<R: BufRead>(mut reader: R, k: usize) -> Result<Vec<(usize, u64)>> {
+    // Ordered so the heap's smallest entry is the worst elf: least calories, then latest elf
+    let mut heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>> = BinaryHeap::with_capacity(k + 1);
+    let mut keep = |elf: usize, total: u64| {
+        heap.push(Reverse((total, Reverse(elf))));
+        if heap.len() > k {
+            heap.pop();
+        }
+    };
+
+    let mut line = String::new();
+    let mut line_no = 0;
+    let mut elf = 1;
+    let mut total = 0;
+    // Blank lines since the last item, trailing ones at the end of the input don't start new elves
+    let mut blanks = 0;
+    let mut has_items = false;
+    loop {
+        line.clear();
+        let read = reader
+            .read_line(&mut line)
+            .wrap_err_with(|| format!("Failed to read line {} of the inventory", line_no + 1))?;
+        if read == 0 {
+            break;
+        }
+        line_no += 1;
+
+        let mut text = line.trim_end_matches(['\r', '\n']);
+        if line_no == 1 {
+            text = text.trim_start_matches('\u{feff}');
+        }
+        if text.is_empty() {
+            blanks += 1;
+            continue;
+        }
+
+        if blanks > 0 {
+            keep(elf, total);
+            // Every extra blank line is an elf carrying nothing
+            for empty in 1..blanks {
+                keep(elf + empty, 0);
+            }
+            elf += blanks;
+            total = 0;
+            blanks = 0;
+        }
+        let calories: u64 = text.parse().map_err(|e| {
+            ParseError::line(DAY, line_no, text, format!("Invalid calories, {}", e))
+        })?;
+        total += calories;
+        has_items = true;
+    }
+    if has_items {
+        keep(elf, total);
+    }
+
+    let mut top: Vec<(usize, u64)> = heap
+        .into_iter()
+        .map(|Reverse((total, Reverse(elf)))| (elf, total))
+        .collect();
+    top.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    Ok(top)
+}
+
+// Total calories carried by the `k` elves carrying the most, see `stream_top_k`
+pub fn stream_top_total<R: BufRead>(reader: R, k: usize) -> Result<u64> {
+    Ok(stream_top_k(reader, k)?.iter().map(|(_, total)| total).sum())
+}
@@ -0,0 +1,52 @@
+use aoc_day1::{parse_into_elves, top_elves_total, Inventory, Rank};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn keeps_each_elfs_items() {
+    let inventory = Inventory::parse(SAMPLE).unwrap();
+    assert_eq!(inventory.len(), 5);
+    assert_eq!(inventory.items(1), Some(&[1000, 2000, 3000][..]));
+    assert_eq!(inventory.items(2), Some(&[4000][..]));
+    assert_eq!(inventory.items(6), None);
+    assert_eq!(inventory.total(4), Some(24000));
+}
+
+#[test]
+fn top_elves() {
+    let inventory = Inventory::parse(SAMPLE).unwrap();
+    assert_eq!(inventory.top(3), vec![(4, 24000), (3, 11000), (5, 10000)]);
+    assert_eq!(inventory.top_total(3), 45000);
+    assert_eq!(inventory.top(10).len(), 5);
+}
+
+#[test]
+fn agrees_with_parse_into_elves() {
+    let elves = parse_into_elves(SAMPLE.to_string()).unwrap();
+    let inventory = Inventory::parse(SAMPLE).unwrap();
+    for n in 0..=5 {
+        assert_eq!(inventory.top_total(n), top_elves_total(&elves, n));
+    }
+}
+
+#[test]
+fn ranking_shares_ranks_between_ties() {
+    let inventory = Inventory::new(vec![vec![5], vec![4, 4], vec![10], vec![8], vec![]]);
+    let ranking: Vec<(usize, usize, u64)> = inventory
+        .ranking()
+        .iter()
+        .map(|&Rank { rank, elf, total }| (rank, elf, total))
+        .collect();
+    assert_eq!(
+        ranking,
+        vec![(1, 3, 10), (2, 2, 8), (2, 4, 8), (4, 1, 5), (5, 5, 0)]
+    );
+    assert_eq!(inventory.rank_of(4), Some(2));
+    assert_eq!(inventory.rank_of(6), None);
+}
+
+#[test]
+fn consecutive_blank_lines_are_an_empty_elf() {
+    let inventory = Inventory::parse("100\n\n\n200\r\n").unwrap();
+    assert_eq!(inventory.elves(), &[vec![100], vec![], vec![200]]);
+}
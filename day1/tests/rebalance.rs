@@ -0,0 +1,107 @@
+use aoc_day1::{Inventory, Move, Strategy, EXACT_ITEM_LIMIT};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+// Apply the moves to the original inventory and check they lead to the rebalanced one
+fn assert_moves_lead_to(original: &Inventory, moves: &[Move], rebalanced: &Inventory) {
+    let mut elves = original.elves().to_vec();
+    for mv in moves {
+        let from = &mut elves[mv.from - 1];
+        let position = from
+            .iter()
+            .position(|&calories| calories == mv.calories)
+            .unwrap_or_else(|| panic!("Elf {} has no {} item to move", mv.from, mv.calories));
+        from.remove(position);
+        elves[mv.to - 1].push(mv.calories);
+    }
+    for (moved, expected) in elves.iter_mut().zip(rebalanced.elves()) {
+        let mut expected = expected.clone();
+        moved.sort_unstable();
+        expected.sort_unstable();
+        assert_eq!(*moved, expected);
+    }
+}
+
+#[test]
+fn sample_exact() {
+    let inventory = Inventory::parse(SAMPLE).unwrap();
+    let rebalanced = inventory.rebalance(Strategy::Exact);
+    // 55000 calories across 5 elves, every item is a multiple of 1000 so 11000 is reachable
+    assert_eq!(rebalanced.max_total, 11000);
+    assert!(rebalanced.optimal);
+    assert_eq!(rebalanced.inventory.len(), 5);
+    assert_moves_lead_to(&inventory, &rebalanced.moves, &rebalanced.inventory);
+}
+
+#[test]
+fn exact_beats_greedy() {
+    // Longest first hands out 3, 3 then 2, 2, 2 alternately for 7, but 3+3 and 2+2+2 split evenly
+    let inventory = Inventory::new(vec![vec![3, 3, 2, 2, 2], vec![]]);
+    let greedy = inventory.rebalance(Strategy::Greedy);
+    let exact = inventory.rebalance(Strategy::Exact);
+    assert_eq!(greedy.max_total, 7);
+    assert_eq!(exact.max_total, 6);
+    assert!(exact.optimal);
+    assert!(!greedy.optimal);
+    assert_moves_lead_to(&inventory, &exact.moves, &exact.inventory);
+}
+
+#[test]
+fn balanced_inventory_needs_no_moves() {
+    let inventory = Inventory::new(vec![vec![5, 1], vec![3, 3], vec![6]]);
+    for strategy in [Strategy::Exact, Strategy::Greedy, Strategy::Auto] {
+        let rebalanced = inventory.rebalance(strategy);
+        assert_eq!(rebalanced.max_total, 6);
+        assert!(rebalanced.moves.is_empty(), "{:?}", rebalanced.moves);
+    }
+}
+
+#[test]
+fn greedy_handles_large_inventories() {
+    let elves: Vec<Vec<u64>> = (0..200)
+        .map(|elf| {
+            (0..elf % 7)
+                .map(|item| (elf * 37 + item * 11) % 5000 + 1)
+                .collect()
+        })
+        .collect();
+    let inventory = Inventory::new(elves);
+    let rebalanced = inventory.rebalance(Strategy::Auto);
+    let total: u64 = inventory.totals().iter().sum();
+    assert_eq!(rebalanced.inventory.totals().iter().sum::<u64>(), total);
+    assert!(rebalanced.max_total <= inventory.top_total(1));
+    assert_moves_lead_to(&inventory, &rebalanced.moves, &rebalanced.inventory);
+}
+
+#[test]
+fn near_balanced_inventory_needs_few_moves() {
+    // Every elf carries 100 but the first, one item away from the rest
+    let mut elves: Vec<Vec<u64>> = (0..30).map(|_| vec![40, 30, 20, 10]).collect();
+    elves[0].push(29);
+    elves[1].pop();
+    elves[1].push(1);
+    let inventory = Inventory::new(elves);
+    let rebalanced = inventory.rebalance(Strategy::Greedy);
+    assert!(rebalanced.max_total < inventory.top_total(1));
+    assert!(rebalanced.moves.len() <= 2, "{:?}", rebalanced.moves);
+    assert_moves_lead_to(&inventory, &rebalanced.moves, &rebalanced.inventory);
+}
+
+#[test]
+fn exact_search_gives_up_without_claiming_optimal() {
+    // Too many items of awkward sizes to search exhaustively within the node budget
+    let items: Vec<u64> = (1..=24).map(|i| (i * 7919) % 60000 + 1000).collect();
+    let elves: Vec<Vec<u64>> = (0..5)
+        .map(|elf| items.iter().skip(elf).step_by(5).copied().collect())
+        .collect();
+    let inventory = Inventory::new(elves);
+    let greedy = inventory.rebalance(Strategy::Greedy);
+    let exact = inventory.rebalance(Strategy::Exact);
+    assert!(!exact.optimal);
+    assert!(exact.max_total <= greedy.max_total);
+    assert_moves_lead_to(&inventory, &exact.moves, &exact.inventory);
+
+    // Auto leaves inventories this size to the greedy strategy
+    assert!(items.len() > EXACT_ITEM_LIMIT);
+    assert_eq!(inventory.rebalance(Strategy::Auto), greedy);
+}
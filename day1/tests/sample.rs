@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use aoc_day1::Day1;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn sample_part_1() {
+    let elves = Day1::parse(SAMPLE).unwrap();
+    assert_eq!(Day1::part_1(&elves).unwrap(), 24000);
+}
+
+#[test]
+fn sample_part_2() {
+    let elves = Day1::parse(SAMPLE).unwrap();
+    assert_eq!(Day1::part_2(&elves).unwrap(), 45000);
+}
@@ -0,0 +1,61 @@
+use aoc_day1::{Bucket, Inventory};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn sample_stats() {
+    let stats = Inventory::parse(SAMPLE).unwrap().stats();
+    assert_eq!(stats.elves, 5);
+    assert_eq!(stats.items, 10);
+    assert_eq!(stats.total, 55000);
+    assert_eq!(stats.mean, 11000.0);
+    assert_eq!(stats.median, 10000.0);
+    // Totals of 6000, 4000, 11000, 24000 and 10000
+    assert!((stats.stddev - 6985.6997).abs() < 0.001, "{}", stats.stddev);
+    assert_eq!(stats.largest_item, Some((5, 10000)));
+    assert!(stats.empty_elves.is_empty());
+    assert_eq!(stats.histogram.iter().map(|b| b.count).sum::<usize>(), 5);
+}
+
+#[test]
+fn empty_elves_and_even_median() {
+    let inventory = Inventory::parse("300\n\n\n100\n200\n\n\n\n500\n").unwrap();
+    let stats = inventory.stats();
+    assert_eq!(stats.empty_elves, vec![2, 4, 5]);
+    assert_eq!(stats.elves, 6);
+    // Totals of 0, 0, 0, 300, 300 and 500
+    assert_eq!(stats.median, 150.0);
+    assert_eq!(stats.largest_item, Some((6, 500)));
+}
+
+#[test]
+fn totals_beyond_u32() {
+    let input = format!("{}\n{}\n\n1\n", u32::MAX, u32::MAX);
+    let stats = Inventory::parse(&input).unwrap().stats();
+    assert_eq!(stats.total, 2 * u64::from(u32::MAX) + 1);
+    assert_eq!(Inventory::parse(&input).unwrap().top_total(1), 2 * u64::from(u32::MAX));
+}
+
+#[test]
+fn histogram_buckets_cover_every_total() {
+    let inventory = Inventory::new(vec![vec![0], vec![5], vec![9], vec![10], vec![10]]);
+    assert_eq!(
+        inventory.histogram(2),
+        vec![
+            Bucket { low: 0, high: 5, count: 2 },
+            Bucket { low: 6, high: 10, count: 3 },
+        ]
+    );
+    let single = Inventory::new(vec![vec![7], vec![7]]);
+    assert_eq!(single.histogram(10), vec![Bucket { low: 7, high: 7, count: 2 }]);
+}
+
+#[test]
+fn report_lists_every_section() {
+    let report = Inventory::parse(SAMPLE).unwrap().stats().to_string();
+    assert!(report.contains("Elves: 5"));
+    assert!(report.contains("median 10000.0"));
+    assert!(report.contains("Largest item: 10000 calories, carried by elf 5"));
+    assert!(report.contains("Elves carrying no items: none"));
+    assert_eq!(report.lines().filter(|line| line.contains(" | ")).count(), 10);
+}
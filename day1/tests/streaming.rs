@@ -0,0 +1,65 @@
+use aoc_common::Rng;
+use aoc_day1::{parse_into_elves, stream_top_k, stream_top_total, top_elves_total, Inventory};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+// Deterministic inventory with ties, runs of blank lines and CRLF line endings
+fn generated_inventory(elves: usize) -> String {
+    let mut rng = Rng::new(2022);
+    let mut input = String::new();
+    for _ in 0..elves {
+        for _ in 0..rng.below(4) {
+            input += &format!("{}\r\n", (rng.below(20) + 1) * 100);
+        }
+        input += "\r\n";
+    }
+    input
+}
+
+#[test]
+fn sample_agrees_with_full_sort() {
+    let elves = parse_into_elves(SAMPLE.to_string()).unwrap();
+    let inventory = Inventory::parse(SAMPLE).unwrap();
+    for k in 0..=6 {
+        assert_eq!(stream_top_k(SAMPLE.as_bytes(), k).unwrap(), inventory.top(k));
+        assert_eq!(
+            stream_top_total(SAMPLE.as_bytes(), k).unwrap(),
+            top_elves_total(&elves, k)
+        );
+    }
+}
+
+#[test]
+fn generated_inventory_agrees_with_full_sort() {
+    let input = generated_inventory(500);
+    let inventory = Inventory::parse(&input).unwrap();
+    for k in [0, 1, 3, 10, 100, 499, 500, 501] {
+        assert_eq!(stream_top_k(input.as_bytes(), k).unwrap(), inventory.top(k));
+    }
+}
+
+#[test]
+fn blank_lines_at_either_end() {
+    let input = "\u{feff}\n\n100\n\n\n200\n\n\n";
+    let inventory = Inventory::parse(input).unwrap();
+    assert_eq!(inventory.len(), 5);
+    assert_eq!(stream_top_k(input.as_bytes(), 10).unwrap(), inventory.top(10));
+}
+
+#[test]
+fn invalid_calories_report_the_line() {
+    let error = stream_top_k("100\n\nlots\n".as_bytes(), 3).unwrap_err();
+    assert!(error.to_string().contains("line 3"), "{}", error);
+}
+
+#[test]
+fn items_beyond_u32() {
+    let large = u64::from(u32::MAX) + 1;
+    let input = format!("{}\n1\n\n2\n", large);
+    let elves = parse_into_elves(input.clone()).unwrap();
+    let inventory = Inventory::parse(&input).unwrap();
+    assert_eq!(inventory.top(1), vec![(1, large + 1)]);
+    assert_eq!(stream_top_k(input.as_bytes(), 1).unwrap(), inventory.top(1));
+    assert_eq!(top_elves_total(&elves, 2), inventory.top_total(2));
+    assert_eq!(inventory.stats().largest_item, Some((1, large)));
+}
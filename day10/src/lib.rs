@@ -1,8 +1,11 @@
 #![allow(dead_code, unused)]
 use std::{collections::VecDeque, fmt};
 
+use aoc_common::{ParseError, Solution};
 use eyre::{eyre, Result};
 
+const DAY: u8 = 10;
+
 #[derive(Debug)]
 pub enum Instruction {
     AddX(i32),
@@ -25,19 +28,33 @@ impl Instruction {
     }
 }
 
-pub fn parse_input(input: String) -> Result<Vec<Instruction>> {
+pub fn parse_input(input: String) -> Result<Vec<Instruction>, ParseError> {
     let mut commands = Vec::new();
-    for line in input.lines() {
+    for (i, line) in input.lines().enumerate() {
         let mut parts = line.split_whitespace();
-        let command = parts.next().ok_or_else(|| eyre!("Unrecognized input!"))?;
+        let command = parts
+            .next()
+            .ok_or_else(|| ParseError::line(DAY, i + 1, line, "Empty instruction"))?;
         match command {
             "addx" => {
-                let val = parts.next().ok_or_else(|| eyre!("Addx missing value!"))?;
-                let val = val.parse::<i32>()?;
+                let val = parts
+                    .next()
+                    .ok_or_else(|| ParseError::line(DAY, i + 1, line, "Addx missing value"))?;
+                let val = val.parse::<i32>().map_err(|_| {
+                    ParseError::at(DAY, i + 1, line, val, "Addx value must be a number")
+                })?;
                 commands.push(Instruction::AddX(val));
             }
             "noop" => commands.push(Instruction::Noop),
-            _ => panic!("Unrecognized command"),
+            _ => {
+                return Err(ParseError::at(
+                    DAY,
+                    i + 1,
+                    line,
+                    command,
+                    "Unrecognized instruction",
+                ));
+            }
         }
     }
     Ok(commands)
@@ -51,11 +68,10 @@ pub fn part_1(commands: &[Instruction]) -> i32 {
     let mut current_command = cmds.next();
     let mut command_cycles = current_command.unwrap().get_cycles();
     let mut signal_strengths = 0;
-    while cmds.len() > 0 || !current_command.is_none() {
+    while cmds.len() > 0 || current_command.is_some() {
         if (cycle_count == 20 || f64::from(cycle_count - 20) / 40.0 % 1.0 == 0.0)
             && cycle_count <= 220
         {
-            println!("Cycle {}: {}", cycle_count, register);
             signal_strengths += register * cycle_count;
         }
         if command_cycles > 0 {
@@ -75,28 +91,23 @@ pub fn part_1(commands: &[Instruction]) -> i32 {
     signal_strengths
 }
 
-// Solution for part 2
-pub fn part_2(commands: &[Instruction]) -> Result<()> {
+// Solution for part 2, returns the image drawn on the CRT one row per line
+pub fn part_2(commands: &[Instruction]) -> Result<String> {
     let mut register = 1;
     let mut cycle_count = 1;
     let mut cmds = commands.iter();
     let mut current_command = cmds.next();
     let mut command_cycles = current_command.unwrap().get_cycles();
     let mut current_line: Vec<char> = vec![];
-    while cmds.len() > 0 || !current_command.is_none() {
-        if is_sprite_visible(register, cycle_count % 40 - 1) {
+    let mut rows: Vec<String> = vec![];
+    while cmds.len() > 0 || current_command.is_some() {
+        if is_sprite_visible(register, (cycle_count - 1) % 40) {
             current_line.push('#');
         } else {
             current_line.push('.');
         }
         if current_line.len() == 40 {
-            println!(
-                "{}",
-                current_line.iter().fold(String::new(), |mut accum, pixel| {
-                    accum.push(*pixel);
-                    accum
-                })
-            );
+            rows.push(current_line.iter().collect());
             current_line = vec![];
         }
         if command_cycles > 0 {
@@ -113,9 +124,46 @@ pub fn part_2(commands: &[Instruction]) -> Result<()> {
         }
         cycle_count += 1;
     }
-    Ok(())
+    Ok(rows.join("\n"))
 }
 
 fn is_sprite_visible(loc: i32, current_pixel: i32) -> bool {
     loc >= current_pixel - 1 && loc <= current_pixel + 1
 }
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = DAY;
+
+    type Parsed = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(aoc_common::normalize(input).into_owned())
+    }
+
+    fn part_1(commands: &Self::Parsed) -> Result<Self::Answer1> {
+        Ok(part_1(commands))
+    }
+
+    fn part_2(commands: &Self::Parsed) -> Result<Self::Answer2> {
+        part_2(commands)
+    }
+
+    fn warnings(commands: &Self::Parsed) -> Vec<String> {
+        let cycles: usize = commands
+            .iter()
+            .map(|cmd| cmd.get_cycles() as usize + 1)
+            .sum();
+        if cycles < 240 {
+            vec![format!(
+                "Program only runs for {} cycles, the CRT needs 240 to draw a full image",
+                cycles
+            )]
+        } else {
+            vec![]
+        }
+    }
+}
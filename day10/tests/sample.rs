@@ -0,0 +1,33 @@
+use aoc_common::Solution;
+use aoc_day10::Day10;
+
+const SAMPLE_2: &str = include_str!("../sample2.txt");
+
+const SAMPLE_2_IMAGE: &str = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+#[test]
+fn sample_2_part_1() {
+    let commands = Day10::parse(SAMPLE_2).unwrap();
+    assert_eq!(Day10::part_1(&commands).unwrap(), 13140);
+}
+
+#[test]
+fn sample_2_part_2() {
+    let commands = Day10::parse(SAMPLE_2).unwrap();
+    assert_eq!(Day10::part_2(&commands).unwrap(), SAMPLE_2_IMAGE);
+}
+
+#[test]
+fn sample_saved_with_crlf_and_trailing_blank_lines() {
+    let input = format!("\u{feff}{}\r\n\r\n", SAMPLE_2.replace('\n', "\r\n"));
+    let expected = Day10::part_1(&Day10::parse(SAMPLE_2).unwrap()).unwrap();
+    let parsed = Day10::parse(&input).unwrap();
+    assert_eq!(Day10::part_1(&parsed).unwrap().to_string(), expected.to_string());
+    assert!(Day10::part_2(&parsed).is_ok());
+}
@@ -1,11 +1,13 @@
 #![allow(dead_code, unused)]
 use std::collections::HashMap;
-use std::fmt::Debug;
 use std::str::FromStr;
 
+use aoc_common::{ParseError, Solution};
 use eyre::{eyre, Result};
 
-#[derive(Debug)]
+const DAY: u8 = 11;
+
+#[derive(Debug, Clone)]
 pub struct Monkey {
     pub id: usize,
     pub items: Vec<Item>,
@@ -23,31 +25,32 @@ impl Monkey {
         }
     }
 
-    fn inspect_items(&mut self, op: &Operation) {
-        for mut item in self.items.iter_mut() {
-            Monkey::inspect_item(&mut item, &op)
+    fn inspect_items(&mut self, relief: Relief) {
+        for item in self.items.iter_mut() {
+            Monkey::inspect_item(item, &self.op);
+            item.relieve(relief);
         }
     }
 
-    fn inspect_item(mut item: &mut Item, op: &Operation) {
+    fn inspect_item(item: &mut Item, op: &Operation) {
         match op {
             Operation::Add(operator) => item.add(operator),
             Operation::Multiply(operator) => item.mult(operator),
         }
     }
 
-    fn throw_items(&mut self, monkeys: &mut Monkeys) {
-        while let Some(item) = self.items.pop() {
-            if item.worry_level % self.test.divisible_by == 0 {
-                if let Some(monkey) = monkeys.get_mut(self.test.true_monkey) {
-                    monkey.items.push(item);
+    // Empty this monkey's hands, returning each item along with the id of the monkey it's thrown to
+    fn throw_items(&mut self) -> Vec<(usize, Item)> {
+        self.items
+            .drain(..)
+            .map(|item| {
+                if item.worry_level % self.test.divisible_by == 0 {
+                    (self.test.true_monkey, item)
+                } else {
+                    (self.test.false_monkey, item)
                 }
-            } else {
-                if let Some(monkey) = monkeys.get_mut(self.test.false_monkey) {
-                    monkey.items.push(item);
-                }
-            }
-        }
+            })
+            .collect()
     }
 
     fn get_items(&self) -> &Vec<Item> {
@@ -73,48 +76,63 @@ impl AsMut<Monkey> for Monkey {
 
 #[derive(Debug, Clone, Copy)]
 pub struct Item {
-    worry_level: i32,
+    worry_level: i64,
 }
 
 impl Item {
-    fn add(&mut self, operator: &String) {
-        if operator.contains("old") {
-            self.worry_level = self.worry_level * 2;
-            self.worry_level = self.worry_level / 3;
-        } else {
-            let operator = operator.parse::<i32>().expect("Expected a number");
-            self.worry_level += operator;
-            self.worry_level = self.worry_level / 3;
+    fn add(&mut self, operator: &Operand) {
+        match operator {
+            Operand::Old => self.worry_level *= 2,
+            Operand::Value(value) => self.worry_level += value,
         }
     }
 
-    fn mult(&mut self, operator: &String) {
-        if operator.contains("old") {
-            self.worry_level = self.worry_level.pow(2);
-            self.worry_level = self.worry_level / 3;
-        } else {
-            let operator = operator.parse::<i32>().expect("Expected a number");
-            self.worry_level *= operator;
-            self.worry_level = self.worry_level / 3;
+    fn mult(&mut self, operator: &Operand) {
+        match operator {
+            Operand::Old => self.worry_level = self.worry_level.pow(2),
+            Operand::Value(value) => self.worry_level *= value,
+        }
+    }
+
+    fn relieve(&mut self, relief: Relief) {
+        match relief {
+            Relief::DivideByThree => self.worry_level /= 3,
+            Relief::Modulo(modulus) => self.worry_level %= modulus,
         }
     }
 }
 
+// How worry levels are kept in check after each inspection
+#[derive(Debug, Clone, Copy)]
+pub enum Relief {
+    // Part 1, worry drops to a third once the monkey loses interest
+    DivideByThree,
+    // Part 2, no relief so worry is kept modulo the product of every test divisor
+    Modulo(i64),
+}
+
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Test {
-    divisible_by: i32,
+    divisible_by: i64,
     true_monkey: usize,
     false_monkey: usize,
 }
 
 #[derive(Debug, Clone)]
 pub enum Operation {
-    Add(String),
-    Multiply(String),
+    Add(Operand),
+    Multiply(Operand),
+}
+
+// Right hand side of an operation, either the item's current worry level or a constant
+#[derive(Debug, Clone, Copy)]
+pub enum Operand {
+    Old,
+    Value(i64),
 }
 
-#[derive(Debug)]
-struct Monkeys {
+#[derive(Debug, Clone)]
+pub struct Monkeys {
     monkeys: HashMap<usize, Monkey>,
 }
 
@@ -129,6 +147,15 @@ impl Monkeys {
         self.monkeys.values().collect()
     }
 
+    fn len(&self) -> usize {
+        self.monkeys.len()
+    }
+
+    // Product of every monkey's test divisor, reducing by it doesn't change any test result
+    pub fn worry_modulus(&self) -> i64 {
+        self.monkeys.values().map(|m| m.test.divisible_by).product()
+    }
+
     fn add_monkey(&mut self, monkey: Monkey) -> usize {
         let index = self.monkeys.len();
         self.monkeys.insert(monkey.id, monkey);
@@ -136,112 +163,255 @@ impl Monkeys {
     }
 
     fn get(&self, index: usize) -> Option<&Monkey> {
-        return if let Some(monkey) = self.monkeys.get(&index) {
-            Some(monkey.as_ref())
+        if let Some(monkey) = self.monkeys.get(&index) {
+            Some(monkey)
         } else {
             None
-        };
+        }
     }
 
     fn get_mut(&mut self, index: usize) -> Option<&mut Monkey> {
-        return if let Some(monkey) = self.monkeys.get_mut(&index) {
-            Some(monkey.as_mut())
+        if let Some(monkey) = self.monkeys.get_mut(&index) {
+            Some(monkey)
         } else {
             None
+        }
+    }
+}
+
+// A monkey's lines read so far, from its `Monkey N:` header up to the next blank line
+struct Block<'a> {
+    line_no: usize,
+    header: &'a str,
+    id: usize,
+    items: Vec<Item>,
+    op: Option<Operation>,
+    divisible_by: Option<i64>,
+    true_monkey: Option<usize>,
+    false_monkey: Option<usize>,
+}
+
+impl<'a> Block<'a> {
+    fn start(line_no: usize, header: &'a str) -> Result<Self, ParseError> {
+        Ok(Block {
+            line_no,
+            header,
+            id: parse_single_number(line_no, header)?,
+            items: vec![],
+            op: None,
+            divisible_by: None,
+            true_monkey: None,
+            false_monkey: None,
+        })
+    }
+
+    // Errors point at the header of a monkey missing one of its lines
+    fn finish(self) -> Result<Monkey, ParseError> {
+        let missing = |what: &str| {
+            ParseError::line(
+                DAY,
+                self.line_no,
+                self.header,
+                format!("Monkey has no {} line", what),
+            )
         };
+        let op = self.op.clone().ok_or_else(|| missing("Operation"))?;
+        let test = Test {
+            divisible_by: self.divisible_by.ok_or_else(|| missing("Test"))?,
+            true_monkey: self.true_monkey.ok_or_else(|| missing("If true"))?,
+            false_monkey: self.false_monkey.ok_or_else(|| missing("If false"))?,
+        };
+        Ok(Monkey::new(self.id, self.items, op, test))
     }
 }
 
-pub fn parse_input(input: String) -> Result<Monkeys> {
+pub fn parse_input(input: String) -> Result<Monkeys, ParseError> {
     let mut monkeys = Monkeys::new();
-    let mut id = 0;
-    let mut items: Vec<Item> = vec![];
-    let mut op = Operation::Add(String::default());
-    let mut test = Test::default();
-    for line in input.lines() {
-        if line.is_empty() {
-            // Push monkey with obtained values then reset values
-            monkeys.add_monkey(Monkey::new(id, items, op, test));
-            id += 1;
-            items = vec![];
-            op = Operation::Add(String::default());
-            test = Test::default();
+    let mut block: Option<Block> = None;
+    for (i, line) in input.lines().enumerate() {
+        let line_no = i + 1;
+        if line.trim().is_empty() {
+            // Blank lines end the current monkey, any more of them are ignored
+            if let Some(block) = block.take() {
+                add_block(&mut monkeys, block)?;
+            }
             continue;
         }
+        if line.trim_start().starts_with("Monkey") {
+            if let Some(block) = block.take() {
+                add_block(&mut monkeys, block)?;
+            }
+            block = Some(Block::start(line_no, line)?);
+            continue;
+        }
+        let Some(block) = block.as_mut() else {
+            return Err(ParseError::line(
+                DAY,
+                line_no,
+                line,
+                "Expected a Monkey header",
+            ));
+        };
         match line {
             line if line.contains("items") => {
-                items = parse_items(line);
+                block.items = parse_items(line_no, line)?;
             }
             line if line.contains("Operation") => {
-                op = parse_operation(line);
+                block.op = Some(parse_operation(line_no, line)?);
             }
             line if line.contains("Test") => {
-                test.divisible_by = parse_single_number::<i32>(line);
+                let divisible_by = parse_single_number::<i64>(line_no, line)?;
+                if divisible_by == 0 {
+                    return Err(ParseError::line(
+                        DAY,
+                        line_no,
+                        line,
+                        "Test can't be divisible by zero",
+                    ));
+                }
+                block.divisible_by = Some(divisible_by);
             }
             line if line.contains("true") => {
-                test.true_monkey = parse_single_number::<usize>(line);
+                block.true_monkey = Some(parse_single_number::<usize>(line_no, line)?);
             }
             line if line.contains("false") => {
-                test.false_monkey = parse_single_number::<usize>(line);
+                block.false_monkey = Some(parse_single_number::<usize>(line_no, line)?);
             }
             _ => {}
         }
     }
+    // The last monkey isn't followed by a blank line if the input doesn't end with one
+    if let Some(block) = block {
+        add_block(&mut monkeys, block)?;
+    }
     Ok(monkeys)
 }
 
-pub fn take_turns(monkeys: &mut Monkeys) {
-    let mut hold_counts: HashMap<usize, i32> = HashMap::new();
-    let iter = monkeys.iter();
-    for _ in 0..20 {
-        for monke in iter {
-            let mut monkey = monkeys.get_mut(monke.id).unwrap();
+fn add_block(monkeys: &mut Monkeys, block: Block) -> Result<(), ParseError> {
+    if monkeys.get(block.id).is_some() {
+        return Err(ParseError::line(
+            DAY,
+            block.line_no,
+            block.header,
+            format!("Monkey {} is defined twice", block.id),
+        ));
+    }
+    monkeys.add_monkey(block.finish()?);
+    Ok(())
+}
+
+// Play the given number of rounds, returning the level of monkey business (the product of the
+//      two highest inspection counts)
+pub fn take_turns(monkeys: &mut Monkeys, rounds: usize, relief: Relief) -> Result<i64> {
+    let mut hold_counts: HashMap<usize, i64> = HashMap::new();
+    for _ in 0..rounds {
+        for id in 0..monkeys.len() {
+            let monkey = monkeys
+                .get_mut(id)
+                .ok_or_else(|| eyre!("No monkey found with id {}", id))?;
             if monkey.items.is_empty() {
                 continue;
             }
             let hold_entry = hold_counts.entry(monkey.id).or_insert(0);
-            *hold_entry += monkey.items.len() as i32;
-            monkey.inspect_items(&monkey.op);
-            monkey.throw_items(monkeys);
+            *hold_entry += monkey.items.len() as i64;
+            monkey.inspect_items(relief);
+            for (target, item) in monkey.throw_items() {
+                monkeys
+                    .get_mut(target)
+                    .ok_or_else(|| eyre!("No monkey found with id {}", target))?
+                    .items
+                    .push(item);
+            }
         }
     }
+    let mut counts: Vec<i64> = hold_counts.into_values().collect();
+    counts.sort_by(|a, b| b.cmp(a));
+    Ok(counts.iter().take(2).product())
 }
 
-fn parse_items(items_line: &str) -> Vec<Item> {
-    items_line
-        .replace(",", "")
-        .split_whitespace()
-        .map(|a| {
-            if a.chars().next().unwrap().is_digit(10) {
-                let val = a.parse::<i32>().unwrap();
-                Some(Item { worry_level: val })
-            } else {
-                None
-            }
+fn parse_items(line_no: usize, items_line: &str) -> Result<Vec<Item>, ParseError> {
+    let (_, items) = items_line
+        .split_once(':')
+        .ok_or_else(|| ParseError::line(DAY, line_no, items_line, "Expected a list of items"))?;
+    items
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            let worry_level = item.parse::<i64>().map_err(|_| {
+                ParseError::at(DAY, line_no, items_line, item, "Item must be a number")
+            })?;
+            Ok(Item { worry_level })
         })
-        .flat_map(|a| a)
         .collect()
 }
 
-fn parse_operation(op_line: &str) -> Operation {
+fn parse_operation(line_no: usize, op_line: &str) -> Result<Operation, ParseError> {
     let mut parts = op_line.split_whitespace().rev();
-    let num = parts.next().unwrap();
-    let op = parts.next().unwrap();
+    let (Some(num), Some(op)) = (parts.next(), parts.next()) else {
+        return Err(ParseError::line(
+            DAY,
+            line_no,
+            op_line,
+            "Expected an operator and an operand",
+        ));
+    };
+    let operand = match num {
+        "old" => Operand::Old,
+        num => Operand::Value(num.parse().map_err(|_| {
+            ParseError::at(
+                DAY,
+                line_no,
+                op_line,
+                num,
+                "Operand must be old or a number",
+            )
+        })?),
+    };
     match op {
-        "*" => Operation::Multiply(num.to_string()),
-        "+" => Operation::Add(num.to_string()),
-        _ => panic!("Unexpected input!"),
-    }
-}
-
-fn parse_single_number<T: FromStr>(line: &str) -> T
-where
-    <T as FromStr>::Err: Debug,
-{
-    let num = line
-        .chars()
-        .skip_while(|e| !e.is_digit(10))
-        .take_while(|e| e.is_digit(10))
-        .fold(String::new(), |acc, ch| acc + ch.to_string().as_str());
-    num.parse::<T>().expect("To parse number")
+        "*" => Ok(Operation::Multiply(operand)),
+        "+" => Ok(Operation::Add(operand)),
+        _ => Err(ParseError::at(
+            DAY,
+            line_no,
+            op_line,
+            op,
+            "Operator must be + or *",
+        )),
+    }
+}
+
+fn parse_single_number<T: FromStr>(line_no: usize, line: &str) -> Result<T, ParseError> {
+    let start = line
+        .find(|c: char| c.is_ascii_digit())
+        .ok_or_else(|| ParseError::line(DAY, line_no, line, "Expected a number"))?;
+    let len = line[start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(line.len() - start);
+    let num = &line[start..start + len];
+    num.parse::<T>()
+        .map_err(|_| ParseError::at(DAY, line_no, line, num, "Number is out of range"))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = DAY;
+
+    type Parsed = Monkeys;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(aoc_common::normalize(input).into_owned())
+    }
+
+    fn part_1(monkeys: &Self::Parsed) -> Result<Self::Answer1> {
+        take_turns(&mut monkeys.clone(), 20, Relief::DivideByThree)
+    }
+
+    fn part_2(monkeys: &Self::Parsed) -> Result<Self::Answer2> {
+        let modulus = monkeys.worry_modulus();
+        take_turns(&mut monkeys.clone(), 10000, Relief::Modulo(modulus))
+    }
 }
@@ -1,16 +1,12 @@
 use eyre::Result;
-use std::{fs, time::Instant};
 
 use aoc_day11 as AoC;
 
 fn main() -> Result<()> {
-    let now = Instant::now();
-    {
-        let input = fs::read_to_string("day11/sample.txt")?;
-        let monkeys = AoC::parse_input(input)?;
-        println!("Parsed input: {:.2?}", now.elapsed());
-        println!("Monkeys: {:?}", monkeys);
-    }
-    println!("Total elapsed: {:.2?}", now.elapsed());
+    let input = aoc_common::read_input("day11/sample.txt")?;
+    let mut monkeys = AoC::parse_input(input)?;
+    let monkey_business = AoC::take_turns(&mut monkeys, 20, AoC::Relief::DivideByThree)?;
+    println!("Monkey business: {}", monkey_business);
+
     Ok(())
 }
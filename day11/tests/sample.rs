@@ -0,0 +1,60 @@
+use aoc_common::Solution;
+use aoc_day11::Day11;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn sample_part_1() {
+    let monkeys = Day11::parse(SAMPLE).unwrap();
+    assert_eq!(Day11::part_1(&monkeys).unwrap(), 10605);
+}
+
+#[test]
+fn sample_part_2() {
+    let monkeys = Day11::parse(SAMPLE).unwrap();
+    assert_eq!(Day11::part_2(&monkeys).unwrap(), 2713310158);
+}
+
+#[test]
+fn sample_without_trailing_blank_line() {
+    let monkeys = Day11::parse(SAMPLE.trim_end()).unwrap();
+    assert_eq!(Day11::part_1(&monkeys).unwrap(), 10605);
+}
+
+#[test]
+fn invalid_operand_is_located() {
+    let input = SAMPLE.replacen("new = old * 19", "new = old * nineteen", 1);
+    let error = Day11::parse(&input).unwrap_err();
+    assert_eq!((error.day, error.line, error.column), (11, 3, 26));
+    assert_eq!(error.text, "nineteen");
+}
+
+#[test]
+fn sample_with_crlf_line_endings() {
+    let monkeys = Day11::parse(&SAMPLE.replace('\n', "\r\n")).unwrap();
+    assert_eq!(Day11::part_1(&monkeys).unwrap(), 10605);
+}
+
+#[test]
+fn extra_blank_lines_add_no_monkeys() {
+    let monkeys = Day11::parse(&format!("\n{}\n\n\n", SAMPLE.replace("\n\n", "\n\n\n"))).unwrap();
+    assert_eq!(monkeys.worry_modulus(), 23 * 19 * 13 * 17);
+    assert_eq!(Day11::part_1(&monkeys).unwrap(), 10605);
+    assert_eq!(Day11::part_2(&monkeys).unwrap(), 2713310158);
+}
+
+#[test]
+fn monkey_missing_its_test_is_located() {
+    let input = SAMPLE.replacen("  Test: divisible by 19\n", "", 1);
+    let error = Day11::parse(&input).unwrap_err();
+    assert_eq!((error.line, error.text.as_str()), (8, "Monkey 1:"));
+    assert_eq!(error.reason, "Monkey has no Test line");
+}
+
+#[test]
+fn monkey_defined_twice() {
+    let input = SAMPLE.replacen("Monkey 1:", "Monkey 0:", 1);
+    let error = Day11::parse(&input).unwrap_err();
+    assert_eq!(error.line, 8);
+    assert_eq!(error.reason, "Monkey 0 is defined twice");
+}
@@ -0,0 +1,126 @@
+use aoc_common::Rng;
+
+use crate::{MatchResult, Rules, Selection};
+
+const MOVES: [Selection; 3] = [Selection::Rock, Selection::Paper, Selection::Scissors];
+
+// A player that picks its moves as the tournament goes, learning from what its opponent plays
+pub trait Bot {
+    // Move for the next round
+    fn play(&mut self) -> Selection;
+
+    // Told the opponent's move once the round has been played
+    fn observe(&mut self, opponent: Selection);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BotKind {
+    // Plays uniformly at random, the baseline any learning bot should beat
+    Random,
+    // Counters the move the opponent has played most often
+    Frequency,
+    // Counters the move the opponent has most often followed their last move with
+    Markov,
+}
+
+impl BotKind {
+    pub const ALL: [BotKind; 3] = [BotKind::Random, BotKind::Frequency, BotKind::Markov];
+
+    // Bots pick at random when they've nothing to go on or their prediction is a tie, so the
+    //      same seed always plays the same way
+    pub fn build(self, seed: u64) -> Box<dyn Bot> {
+        let rng = Rng::new(seed);
+        match self {
+            BotKind::Random => Box::new(RandomBot { rng }),
+            BotKind::Frequency => Box::new(FrequencyBot {
+                counts: [0; 3],
+                rng,
+            }),
+            BotKind::Markov => Box::new(MarkovBot {
+                transitions: [[0; 3]; 3],
+                last: None,
+                fallback: FrequencyBot {
+                    counts: [0; 3],
+                    rng,
+                },
+            }),
+        }
+    }
+}
+
+// The move that beats `predicted`
+pub fn counter(predicted: Selection) -> Selection {
+    Rules::classic()
+        .respond(predicted.index(), MatchResult::Win)
+        .and_then(Selection::from_index)
+        .unwrap_or(Selection::Rock)
+}
+
+struct RandomBot {
+    rng: Rng,
+}
+
+impl Bot for RandomBot {
+    fn play(&mut self) -> Selection {
+        MOVES[self.rng.below(MOVES.len())]
+    }
+
+    fn observe(&mut self, _opponent: Selection) {}
+}
+
+struct FrequencyBot {
+    counts: [u32; 3],
+    rng: Rng,
+}
+
+impl FrequencyBot {
+    fn predict(&mut self, counts: &[u32; 3]) -> Selection {
+        let most = counts.iter().max().copied().unwrap_or(0);
+        let likely: Vec<Selection> = MOVES
+            .iter()
+            .zip(counts)
+            .filter(|(_, &count)| count == most)
+            .map(|(&mv, _)| mv)
+            .collect();
+        likely[self.rng.below(likely.len())]
+    }
+}
+
+impl Bot for FrequencyBot {
+    fn play(&mut self) -> Selection {
+        let counts = self.counts;
+        counter(self.predict(&counts))
+    }
+
+    fn observe(&mut self, opponent: Selection) {
+        self.counts[opponent.index()] += 1;
+    }
+}
+
+struct MarkovBot {
+    // `transitions[a][b]` counts how often the opponent played `b` straight after `a`
+    transitions: [[u32; 3]; 3],
+    last: Option<Selection>,
+    // Used until the opponent's last move has been seen followed by anything
+    fallback: FrequencyBot,
+}
+
+impl Bot for MarkovBot {
+    fn play(&mut self) -> Selection {
+        match self.last {
+            Some(last) if self.transitions[last.index()].iter().any(|&n| n > 0) => {
+                let counts = self.transitions[last.index()];
+                counter(self.fallback.predict(&counts))
+            }
+            _ => self.fallback.play(),
+        }
+    }
+
+    fn observe(&mut self, opponent: Selection) {
+        if let Some(last) = self.last {
+            self.transitions[last.index()][opponent.index()] += 1;
+        }
+        self.last = Some(opponent);
+        self.fallback.observe(opponent);
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt::{Display, Formatter};
+use std::io::{self, Write};
+
+use crate::{selection_moves, MatchResult, Rules, Selection, CLASSIC_MOVES};
+
+// A single round of the tournament along with the scores after it, rounds are numbered from 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LedgerEntry {
+    pub round: usize,
+    pub opp: Selection,
+    pub user: Selection,
+    // Result for the user
+    pub result: MatchResult,
+    pub opp_points: u32,
+    pub user_points: u32,
+    pub opp_total: u32,
+    pub user_total: u32,
+}
+
+// The user's wins, losses and draws over a tournament
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub wins: usize,
+    pub losses: usize,
+    pub draws: usize,
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} won, {} lost, {} drawn",
+            self.wins, self.losses, self.draws
+        )
+    }
+}
+
+// Round by round account of a tournament, scored like `eval_tournament` or `eval_tournament_with`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ledger {
+    entries: Vec<LedgerEntry>,
+}
+
+impl Ledger {
+    // Scored under the puzzle's rules
+    pub fn new(rounds: &[(Selection, Selection)]) -> Self {
+        Ledger::score(rounds, Rules::classic(), CLASSIC_MOVES)
+    }
+
+    // Scored under any rules, see `eval_tournament_with`
+    pub fn with_rules(rounds: &[(Selection, Selection)], rules: &Rules) -> eyre::Result<Self> {
+        Ok(Ledger::score(rounds, rules, selection_moves(rules)?))
+    }
+
+    fn score(rounds: &[(Selection, Selection)], rules: &Rules, moves: [usize; 3]) -> Self {
+        let mut entries = Vec::with_capacity(rounds.len());
+        let (mut opp_total, mut user_total) = (0, 0);
+        for (i, &(opp, user)) in rounds.iter().enumerate() {
+            let (opp_move, user_move) = (moves[opp.index()], moves[user.index()]);
+            let (opp_points, user_points) = rules.score(opp_move, user_move);
+            opp_total += opp_points;
+            user_total += user_points;
+            entries.push(LedgerEntry {
+                round: i + 1,
+                opp,
+                user,
+                result: rules.result(user_move, opp_move),
+                opp_points,
+                user_points,
+                opp_total,
+                user_total,
+            });
+        }
+        Ledger { entries }
+    }
+
+    pub fn entries(&self) -> &[LedgerEntry] {
+        &self.entries
+    }
+
+    // Final (opponent, user) scores
+    pub fn totals(&self) -> (u32, u32) {
+        self.entries
+            .last()
+            .map_or((0, 0), |entry| (entry.opp_total, entry.user_total))
+    }
+
+    pub fn summary(&self) -> Summary {
+        let mut summary = Summary::default();
+        for entry in &self.entries {
+            match entry.result {
+                MatchResult::Win => summary.wins += 1,
+                MatchResult::Lose => summary.losses += 1,
+                MatchResult::Draw => summary.draws += 1,
+            }
+        }
+        summary
+    }
+
+    // First round where the user scores differently than in `other`, like two decodings of the
+    //      same guide
+    pub fn first_divergence(&self, other: &Ledger) -> Option<usize> {
+        self.entries
+            .iter()
+            .zip(&other.entries)
+            .find(|(a, b)| a.user_points != b.user_points)
+            .map(|(entry, _)| entry.round)
+    }
+
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(
+            writer,
+            "round,opponent,user,result,opponent_points,user_points,opponent_total,user_total"
+        )?;
+        for entry in &self.entries {
+            writeln!(
+                writer,
+                "{},{:?},{:?},{:?},{},{},{},{}",
+                entry.round,
+                entry.opp,
+                entry.user,
+                entry.result,
+                entry.opp_points,
+                entry.user_points,
+                entry.opp_total,
+                entry.user_total
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = Vec::new();
+        self.write_csv(&mut csv)
+            .expect("Writing to a Vec can't fail");
+        String::from_utf8(csv).expect("CSV is built from UTF-8")
+    }
+}
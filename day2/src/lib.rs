@@ -1,110 +1,148 @@
-use std::error::Error;
-use std::fmt::{Display, Formatter};
-use crate::ParseError::ParseRoundError;
+use aoc_common::{ParseError, Solution};
 
-#[derive(Debug)]
+mod bots;
+mod ledger;
+mod rules;
+mod simulate;
+
+pub use aoc_common::Rng;
+pub use bots::{counter, Bot, BotKind};
+pub use ledger::{Ledger, LedgerEntry, Summary};
+pub use rules::{MoveDef, OutcomePoints, Rules};
+pub use simulate::{play_guide, GuideScore, HeadToHead, Report, Simulation};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Selection {
     Rock,
     Paper,
     Scissors
 }
 
-#[derive(Debug)]
+impl Selection {
+    // Index of the move in `Rules::rock_paper_scissors`
+    pub fn index(self) -> usize {
+        match self {
+            Selection::Rock => 0,
+            Selection::Paper => 1,
+            Selection::Scissors => 2,
+        }
+    }
+
+    // Name of the move in a ruleset, see `Rules::find`
+    pub fn name(self) -> &'static str {
+        match self {
+            Selection::Rock => "Rock",
+            Selection::Paper => "Paper",
+            Selection::Scissors => "Scissors",
+        }
+    }
+
+    pub fn from_index(index: usize) -> Option<Selection> {
+        match index {
+            0 => Some(Selection::Rock),
+            1 => Some(Selection::Paper),
+            2 => Some(Selection::Scissors),
+            _ => None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MatchResult {
     Win,
     Lose,
     Draw
 }
 
-#[derive(Debug)]
-pub enum ParseError {
-    ParseTournamentError,
-    ParseRoundError,
-    ParseChoiceError
+const DAY: u8 = 2;
+
+// How the second column of the strategy guide is read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeStrategy {
+    // X, Y and Z are the move to play: rock, paper and scissors (part 1 interpretation)
+    Move,
+    // X, Y and Z are how the round needs to end: lose, draw and win (part 2 interpretation)
+    Outcome
 }
 
-impl Display for ParseError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Error while parsing input")
+impl DecodeStrategy {
+    // The user's move for a second column of `column` against the opponent's `opp`
+    pub fn decode(self, column: &str, opp: Selection) -> Option<Selection> {
+        match self {
+            DecodeStrategy::Move => match column {
+                "X" => Some(Selection::Rock),
+                "Y" => Some(Selection::Paper),
+                "Z" => Some(Selection::Scissors),
+                _ => None
+            },
+            DecodeStrategy::Outcome => {
+                let result = match column {
+                    "X" => MatchResult::Lose,
+                    "Y" => MatchResult::Draw,
+                    "Z" => MatchResult::Win,
+                    _ => return None
+                };
+                Rules::classic()
+                    .respond(opp.index(), result)
+                    .and_then(Selection::from_index)
+            }
+        }
     }
 }
 
-impl Error for ParseError {}
-
-pub fn parse_tournament(input: &[&str]) -> Result<Vec<(Selection, Selection)>, ParseError> {
-    let mut rounds: Vec<(Selection, Selection)> = Vec::new();
+pub fn parse_tournament(input: &[&str], strategy: DecodeStrategy) -> Result<Vec<(Selection, Selection)>, ParseError> {
     input.iter()
-        .for_each(|raw_round| rounds.push(parse_round(raw_round).unwrap()));
-
-    Ok(rounds)
+        .enumerate()
+        .map(|(i, raw_round)| parse_round(i + 1, raw_round, strategy))
+        .collect()
 }
 
-fn parse_round(round: &str) -> Result<(Selection, Selection), ParseError> {
+fn split_round(line: usize, round: &str) -> Result<(&str, &str), ParseError> {
     let mut split_string = round.split_whitespace();
-    let opp = split_string.next().ok_or(ParseRoundError)?;
-    let user = split_string.next().ok_or(ParseRoundError)?;
-
-    let opp = parse_opp_choice(opp)?;
-    let user = decode_user_choice(user, &opp)?;
+    let opp = split_string.next()
+        .ok_or_else(|| ParseError::line(DAY, line, round, "Round is empty"))?;
+    let user = split_string.next()
+        .ok_or_else(|| ParseError::line(DAY, line, round, "Round is missing the user's column"))?;
 
     Ok((opp, user))
 }
 
-/* Method for parsing part 1 inputs. Unused but keeping around for documentation of part1 work
-fn parse_choice(choice: &str) -> Result<Selection, ParseError> {
-    match choice {
-        "A" => Ok(Selection::Rock),
-        "X" => Ok(Selection::Rock),
-        "B" => Ok(Selection::Paper),
-        "Y" => Ok(Selection::Paper),
-        "C" => Ok(Selection::Scissors),
-        "Z" => Ok(Selection::Scissors),
-        _ => Err(ParseError::ParseChoiceError)
-    }
-} */
+fn parse_round(line: usize, round: &str, strategy: DecodeStrategy) -> Result<(Selection, Selection), ParseError> {
+    let (opp, user) = split_round(line, round)?;
+
+    let opp_choice = parse_opp_choice(opp)
+        .ok_or_else(|| ParseError::at(DAY, line, round, opp, "Expected A, B or C"))?;
+    let user_choice = strategy.decode(user, opp_choice)
+        .ok_or_else(|| ParseError::at(DAY, line, round, user, "Expected X, Y or Z"))?;
 
-fn parse_opp_choice(choice: &str) -> Result<Selection, ParseError> {
+    Ok((opp_choice, user_choice))
+}
+
+fn parse_opp_choice(choice: &str) -> Option<Selection> {
     match choice {
-        "A" => Ok(Selection::Rock),
-        "B" => Ok(Selection::Paper),
-        "C" => Ok(Selection::Scissors),
-        _ => Err(ParseError::ParseChoiceError)
+        "A" => Some(Selection::Rock),
+        "B" => Some(Selection::Paper),
+        "C" => Some(Selection::Scissors),
+        _ => None
     }
 }
 
-fn decode_user_choice(choice: &str, opp: &Selection) -> Result<Selection, ParseError> {
-    let selection= match choice {
-        "X" => {
-            match opp {
-                Selection::Rock => Selection::Scissors,
-                Selection::Paper => Selection::Rock,
-                Selection::Scissors => Selection::Paper,
-            }
-        },
-        "Y" => {
-            match opp {
-                Selection::Rock => Selection::Rock,
-                Selection::Paper => Selection::Paper,
-                Selection::Scissors => Selection::Scissors,
-            }
-        },
-        "Z" => {
-            match opp {
-                Selection::Rock => Selection::Paper,
-                Selection::Paper => Selection::Scissors,
-                Selection::Scissors => Selection::Rock,
-            }
-        },
-        _ => panic!("UNPARSEABLE INPUTS: {}, {:?}", choice, opp)
-    };
-    Ok(selection)
+// (opponent, user) totals under the puzzle's rules
+pub fn eval_tournament(rounds: &[(Selection, Selection)]) -> (u32, u32) {
+    score_tournament(Rules::classic(), CLASSIC_MOVES, rounds)
+}
+
+// (opponent, user) totals under any rules, rock, paper and scissors are scored as the rules' moves
+//      of the same name
+pub fn eval_tournament_with(rules: &Rules, rounds: &[(Selection, Selection)]) -> eyre::Result<(u32, u32)> {
+    Ok(score_tournament(rules, selection_moves(rules)?, rounds))
 }
 
-pub fn eval_tournament(rounds: Vec<(Selection, Selection)>) -> (u32, u32) {
+fn score_tournament(rules: &Rules, moves: [usize; 3], rounds: &[(Selection, Selection)]) -> (u32, u32) {
     let [mut opp, mut user] = [0; 2];
 
-    for (opp_choice, user_choice) in rounds {
-        let (opp_score, user_score) = eval_round(opp_choice, user_choice);
+    for &(opp_choice, user_choice) in rounds {
+        let (opp_score, user_score) = rules.score(moves[opp_choice.index()], moves[user_choice.index()]);
         opp += opp_score;
         user += user_score;
     }
@@ -112,39 +150,46 @@ pub fn eval_tournament(rounds: Vec<(Selection, Selection)>) -> (u32, u32) {
     (opp, user)
 }
 
-fn eval_round(opp: Selection, user: Selection) -> (u32, u32) {
-    let (opp_points, user_points);
-
-    let (opp_result, user_result) = match (&opp, &user) {
-        (Selection::Rock, Selection::Rock) => (MatchResult::Draw, MatchResult::Draw),
-        (Selection::Rock, Selection::Paper) => (MatchResult::Lose, MatchResult::Win),
-        (Selection::Rock, Selection::Scissors) => (MatchResult::Win, MatchResult::Lose),
-        (Selection::Paper, Selection::Rock) => (MatchResult::Win, MatchResult::Lose),
-        (Selection::Paper, Selection::Paper) => (MatchResult::Draw, MatchResult::Draw),
-        (Selection::Paper, Selection::Scissors) => (MatchResult::Lose, MatchResult::Win),
-        (Selection::Scissors, Selection::Rock) => (MatchResult::Lose, MatchResult::Win),
-        (Selection::Scissors, Selection::Paper) => (MatchResult::Win, MatchResult::Lose),
-        (Selection::Scissors, Selection::Scissors) => (MatchResult::Draw, MatchResult::Draw),
-    };
-
-    opp_points = selection_points(opp) + result_points(opp_result);
-    user_points = selection_points(user) + result_points(user_result);
-
-    (opp_points, user_points)
+// (opponent, user) points for a single round under the puzzle's rules
+pub fn eval_round(opp: Selection, user: Selection) -> (u32, u32) {
+    Rules::classic().score(opp.index(), user.index())
 }
 
-fn selection_points(selection: Selection) -> u32 {
-    match selection {
-        Selection::Rock => 1,
-        Selection::Paper => 2,
-        Selection::Scissors => 3,
+// `Rules::classic` lists its moves in the same order as `Selection`
+const CLASSIC_MOVES: [usize; 3] = [0, 1, 2];
+
+// Each selection's move in a ruleset, by `Selection::index`, looked up by name so the rules can
+//      list their moves in any order
+fn selection_moves(rules: &Rules) -> eyre::Result<[usize; 3]> {
+    let mut moves = CLASSIC_MOVES;
+    for selection in [Selection::Rock, Selection::Paper, Selection::Scissors] {
+        moves[selection.index()] = rules.find(selection.name())
+            .ok_or_else(|| eyre::eyre!("Rules have no {} move to score the guide with", selection.name()))?;
     }
+    Ok(moves)
 }
 
-fn result_points(result: MatchResult) -> u32 {
-    match result {
-        MatchResult::Win => 6,
-        MatchResult::Lose => 0,
-        MatchResult::Draw => 3
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = DAY;
+
+    // The strategy guide decoded as moves (part 1) and as outcomes (part 2)
+    type Parsed = (Vec<(Selection, Selection)>, Vec<(Selection, Selection)>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let input = aoc_common::normalize(input);
+        let rounds: Vec<&str> = input.lines().collect();
+        Ok((parse_tournament(&rounds, DecodeStrategy::Move)?, parse_tournament(&rounds, DecodeStrategy::Outcome)?))
+    }
+
+    fn part_1((moves, _): &Self::Parsed) -> eyre::Result<Self::Answer1> {
+        Ok(eval_tournament(moves).1)
+    }
+
+    fn part_2((_, outcomes): &Self::Parsed) -> eyre::Result<Self::Answer2> {
+        Ok(eval_tournament(outcomes).1)
     }
-}
\ No newline at end of file
+}
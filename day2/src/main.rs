@@ -1,24 +1,24 @@
-use std::{fs};
 use std::error::Error;
 
 use aoc_day2 as AoC;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    use std::time::Instant;
-    let now = Instant::now();
-    {
-        let input = fs::read_to_string("day2/day2.txt")?;
+    let input = aoc_common::read_input("day2/day2.txt")?;
 
-        let rounds: Vec<&str> = input.lines().collect();
+    let rounds: Vec<&str> = input.lines().collect();
 
-        let rounds = AoC::parse_tournament(&rounds)?;
+    for strategy in [AoC::DecodeStrategy::Move, AoC::DecodeStrategy::Outcome] {
+        let decoded = AoC::parse_tournament(&rounds, strategy)?;
 
-        let (opp, user) = AoC::eval_tournament(rounds);
+        let ledger = AoC::Ledger::new(&decoded);
+        let (opp, user) = ledger.totals();
 
-        println!("Final score: {} - {}", opp, user);
+        println!("Final score reading the guide as {:?}: {} - {} ({})",
+                 strategy, opp, user, ledger.summary());
     }
-    let elapsed = now.elapsed();
-    println!("Elapsed: {:.2?}", elapsed);
-    
+
+    let guide = AoC::parse_tournament(&rounds, AoC::DecodeStrategy::Outcome)?;
+    print!("{}", AoC::Simulation::default().run(&guide));
+
     Ok(())
 }
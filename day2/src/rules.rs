@@ -0,0 +1,227 @@
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use eyre::{eyre, Result, WrapErr};
+use serde::Deserialize;
+
+use crate::MatchResult;
+
+// Points for the result of a round, on top of the points for the move played
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct OutcomePoints {
+    pub win: u32,
+    pub draw: u32,
+    pub lose: u32,
+}
+
+impl Default for OutcomePoints {
+    fn default() -> Self {
+        OutcomePoints {
+            win: 6,
+            draw: 3,
+            lose: 0,
+        }
+    }
+}
+
+// A move as written in a rules file, `beats` names the moves it wins against
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct MoveDef {
+    pub name: String,
+    pub points: u32,
+    #[serde(default)]
+    pub beats: Vec<String>,
+}
+
+impl MoveDef {
+    pub fn new(name: &str, points: u32, beats: &[&str]) -> Self {
+        MoveDef {
+            name: name.to_string(),
+            points,
+            beats: beats.iter().map(|b| b.to_string()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RulesFile {
+    #[serde(default)]
+    points: OutcomePoints,
+    moves: Vec<MoveDef>,
+}
+
+// The rules of a hand game: the moves, which move beats which and the points awarded. Moves are
+//      referred to by their index in the order they were defined. Pairs of moves where neither
+//      beats the other are a draw.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    points: Vec<u32>,
+    // `beats[a][b]` is true if move `a` wins against move `b`
+    beats: Vec<Vec<bool>>,
+    outcome: OutcomePoints,
+}
+
+impl Rules {
+    pub fn new(outcome: OutcomePoints, moves: Vec<MoveDef>) -> Result<Self> {
+        if moves.is_empty() {
+            return Err(eyre!("Rules need at least one move"));
+        }
+        let mut seen = HashSet::new();
+        for def in &moves {
+            if !seen.insert(def.name.as_str()) {
+                return Err(eyre!("Move {} is defined more than once", def.name));
+            }
+        }
+
+        let names: Vec<String> = moves.iter().map(|def| def.name.clone()).collect();
+        let mut beats = vec![vec![false; moves.len()]; moves.len()];
+        for (winner, def) in moves.iter().enumerate() {
+            for beaten in &def.beats {
+                let loser = names
+                    .iter()
+                    .position(|name| name == beaten)
+                    .ok_or_else(|| eyre!("{} beats {}, which isn't a move", def.name, beaten))?;
+                if loser == winner {
+                    return Err(eyre!("{} can't beat itself", def.name));
+                }
+                beats[winner][loser] = true;
+            }
+        }
+        for a in 0..names.len() {
+            for b in a + 1..names.len() {
+                if beats[a][b] && beats[b][a] {
+                    return Err(eyre!(
+                        "{} and {} can't both beat each other",
+                        names[a],
+                        names[b]
+                    ));
+                }
+            }
+        }
+
+        Ok(Rules {
+            names,
+            points: moves.iter().map(|def| def.points).collect(),
+            beats,
+            outcome,
+        })
+    }
+
+    // Rules in the format of `rules/rock_paper_scissors_lizard_spock.toml`. Outcome points default
+    //      to those of the puzzle if the `[points]` table is left out.
+    pub fn from_toml(rules: &str) -> Result<Self> {
+        let file: RulesFile = toml::from_str(rules).wrap_err("Failed to parse rules")?;
+        Rules::new(file.points, file.moves)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let rules = aoc_common::read_input(path)?;
+        Rules::from_toml(&rules).wrap_err_with(|| format!("Invalid rules in {}", path.display()))
+    }
+
+    // The rules of the puzzle, moves are in the same order as `Selection`
+    pub fn rock_paper_scissors() -> Self {
+        Rules::new(
+            OutcomePoints::default(),
+            vec![
+                MoveDef::new("Rock", 1, &["Scissors"]),
+                MoveDef::new("Paper", 2, &["Rock"]),
+                MoveDef::new("Scissors", 3, &["Paper"]),
+            ],
+        )
+        .expect("Rock-Paper-Scissors rules are valid")
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Rules::new(
+            OutcomePoints::default(),
+            vec![
+                MoveDef::new("Rock", 1, &["Scissors", "Lizard"]),
+                MoveDef::new("Paper", 2, &["Rock", "Spock"]),
+                MoveDef::new("Scissors", 3, &["Paper", "Lizard"]),
+                MoveDef::new("Lizard", 4, &["Spock", "Paper"]),
+                MoveDef::new("Spock", 5, &["Scissors", "Rock"]),
+            ],
+        )
+        .expect("Rock-Paper-Scissors-Lizard-Spock rules are valid")
+    }
+
+    // Shared copy of the puzzle's rules, so scoring a round doesn't build them every time
+    pub fn classic() -> &'static Rules {
+        static CLASSIC: OnceLock<Rules> = OnceLock::new();
+        CLASSIC.get_or_init(Rules::rock_paper_scissors)
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn name(&self, mv: usize) -> &str {
+        &self.names[mv]
+    }
+
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+
+    pub fn beats(&self, a: usize, b: usize) -> bool {
+        self.beats[a][b]
+    }
+
+    // Result of a round for the player of `mv`
+    pub fn result(&self, mv: usize, against: usize) -> MatchResult {
+        if self.beats[mv][against] {
+            MatchResult::Win
+        } else if self.beats[against][mv] {
+            MatchResult::Lose
+        } else {
+            MatchResult::Draw
+        }
+    }
+
+    // The first move that gets `result` against `against`, if there's one
+    pub fn respond(&self, against: usize, result: MatchResult) -> Option<usize> {
+        (0..self.len()).find(|&mv| self.result(mv, against) == result)
+    }
+
+    pub fn move_points(&self, mv: usize) -> u32 {
+        self.points[mv]
+    }
+
+    pub fn result_points(&self, result: MatchResult) -> u32 {
+        match result {
+            MatchResult::Win => self.outcome.win,
+            MatchResult::Lose => self.outcome.lose,
+            MatchResult::Draw => self.outcome.draw,
+        }
+    }
+
+    // (opponent, user) points for a single round
+    pub fn score(&self, opp: usize, user: usize) -> (u32, u32) {
+        (
+            self.move_points(opp) + self.result_points(self.result(opp, user)),
+            self.move_points(user) + self.result_points(self.result(user, opp)),
+        )
+    }
+
+    // (opponent, user) totals over every round
+    pub fn score_tournament(&self, rounds: &[(usize, usize)]) -> (u32, u32) {
+        rounds.iter().fold((0, 0), |(opp, user), &(o, u)| {
+            let (opp_points, user_points) = self.score(o, u);
+            (opp + opp_points, user + user_points)
+        })
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules::rock_paper_scissors()
+    }
+}
@@ -0,0 +1,147 @@
+use std::fmt::{Display, Formatter};
+
+use aoc_common::Rng;
+
+use crate::bots::BotKind;
+use crate::{eval_round, eval_tournament, Ledger, MatchResult, Rules, Selection, Summary};
+
+// A bot playing the user's side of the guide, against the opponent's moves from column A
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuideScore {
+    pub bot: BotKind,
+    pub points: u32,
+    pub summary: Summary,
+}
+
+// Result of two bots playing each other over every tournament of a simulation, from `a`'s side
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeadToHead {
+    pub a: BotKind,
+    pub b: BotKind,
+    pub a_points: u64,
+    pub b_points: u64,
+    pub summary: Summary,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    // The user's score following the guide itself, for comparison with the bots
+    pub guide_points: u32,
+    pub against_guide: Vec<GuideScore>,
+    pub head_to_head: Vec<HeadToHead>,
+}
+
+// Pit every bot against the guide and each other. Each head to head is played over `tournaments`
+//      tournaments of `rounds` rounds, with the bots seeded from `seed` so a simulation can be
+//      repeated exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Simulation {
+    pub seed: u64,
+    pub tournaments: usize,
+    pub rounds: usize,
+}
+
+impl Default for Simulation {
+    fn default() -> Self {
+        Simulation {
+            seed: 2022,
+            tournaments: 100,
+            rounds: 1000,
+        }
+    }
+}
+
+impl Simulation {
+    pub fn run(&self, guide: &[(Selection, Selection)]) -> Report {
+        let against_guide = BotKind::ALL
+            .iter()
+            .map(|&bot| {
+                let ledger = play_guide(guide, bot, self.seed);
+                GuideScore {
+                    bot,
+                    points: ledger.totals().1,
+                    summary: ledger.summary(),
+                }
+            })
+            .collect();
+
+        let mut head_to_head = Vec::new();
+        for (i, &a) in BotKind::ALL.iter().enumerate() {
+            for &b in &BotKind::ALL[i + 1..] {
+                head_to_head.push(self.head_to_head(a, b));
+            }
+        }
+
+        Report {
+            guide_points: eval_tournament(guide).1,
+            against_guide,
+            head_to_head,
+        }
+    }
+
+    pub fn head_to_head(&self, a: BotKind, b: BotKind) -> HeadToHead {
+        let mut result = HeadToHead {
+            a,
+            b,
+            a_points: 0,
+            b_points: 0,
+            summary: Summary::default(),
+        };
+        let mut seeds = Rng::new(self.seed);
+        for _ in 0..self.tournaments {
+            let mut bot_a = a.build(seeds.next_u64());
+            let mut bot_b = b.build(seeds.next_u64());
+            for _ in 0..self.rounds {
+                let (move_a, move_b) = (bot_a.play(), bot_b.play());
+                bot_a.observe(move_b);
+                bot_b.observe(move_a);
+
+                let (points_a, points_b) = eval_round(move_a, move_b);
+                result.a_points += u64::from(points_a);
+                result.b_points += u64::from(points_b);
+                match Rules::classic().result(move_a.index(), move_b.index()) {
+                    MatchResult::Win => result.summary.wins += 1,
+                    MatchResult::Lose => result.summary.losses += 1,
+                    MatchResult::Draw => result.summary.draws += 1,
+                }
+            }
+        }
+        result
+    }
+}
+
+// Ledger of a bot playing the user's side of the guide, seeing each of the opponent's moves only
+//      once the round is over
+pub fn play_guide(guide: &[(Selection, Selection)], bot: BotKind, seed: u64) -> Ledger {
+    let mut bot = bot.build(seed);
+    let rounds: Vec<(Selection, Selection)> = guide
+        .iter()
+        .map(|&(opp, _)| {
+            let user = bot.play();
+            bot.observe(opp);
+            (opp, user)
+        })
+        .collect();
+    Ledger::new(&rounds)
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Following the guide scores {}", self.guide_points)?;
+        for score in &self.against_guide {
+            writeln!(
+                f,
+                "{:?} bot against the guide scores {} ({})",
+                score.bot, score.points, score.summary
+            )?;
+        }
+        for game in &self.head_to_head {
+            writeln!(
+                f,
+                "{:?} vs {:?}: {} - {} ({})",
+                game.a, game.b, game.a_points, game.b_points, game.summary
+            )?;
+        }
+        Ok(())
+    }
+}
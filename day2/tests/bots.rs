@@ -0,0 +1,97 @@
+use aoc_day2::{
+    counter, eval_tournament, parse_tournament, play_guide, BotKind, DecodeStrategy, Ledger,
+    Selection, Simulation,
+};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+// Rounds of `bot` against an opponent that plays `moves` over and over
+fn against(bot: BotKind, moves: &[Selection], rounds: usize) -> Ledger {
+    let guide: Vec<(Selection, Selection)> = moves
+        .iter()
+        .cycle()
+        .take(rounds)
+        .map(|&mv| (mv, mv))
+        .collect();
+    play_guide(&guide, bot, 7)
+}
+
+#[test]
+fn counter_beats_prediction() {
+    assert_eq!(counter(Selection::Rock), Selection::Paper);
+    assert_eq!(counter(Selection::Paper), Selection::Scissors);
+    assert_eq!(counter(Selection::Scissors), Selection::Rock);
+}
+
+#[test]
+fn frequency_bot_beats_constant_opponent() {
+    let ledger = against(BotKind::Frequency, &[Selection::Scissors], 100);
+    // Only the first round is a guess
+    assert!(ledger.summary().wins >= 99);
+    assert!(ledger.entries()[1..]
+        .iter()
+        .all(|entry| entry.user == Selection::Rock));
+}
+
+#[test]
+fn markov_bot_beats_cycling_opponent() {
+    let cycle = [Selection::Rock, Selection::Paper, Selection::Scissors];
+    let markov = against(BotKind::Markov, &cycle, 300);
+    // Once every transition has been seen, each move is predicted exactly
+    assert!(markov.entries()[3..]
+        .iter()
+        .all(|entry| entry.user == counter(entry.opp)));
+
+    let frequency = against(BotKind::Frequency, &cycle, 300);
+    assert!(markov.totals().1 > frequency.totals().1);
+}
+
+#[test]
+fn guide_scores_match_ledger() {
+    let rounds: Vec<&str> = SAMPLE.lines().collect();
+    let guide = parse_tournament(&rounds, DecodeStrategy::Outcome).unwrap();
+    let report = Simulation::default().run(&guide);
+
+    assert_eq!(report.guide_points, eval_tournament(&guide).1);
+    for score in &report.against_guide {
+        let ledger = play_guide(&guide, score.bot, Simulation::default().seed);
+        assert_eq!(score.points, ledger.totals().1);
+        assert_eq!(score.summary, ledger.summary());
+    }
+}
+
+#[test]
+fn simulation_is_deterministic() {
+    let simulation = Simulation {
+        seed: 42,
+        tournaments: 5,
+        rounds: 50,
+    };
+    let first = simulation.head_to_head(BotKind::Random, BotKind::Markov);
+    assert_eq!(
+        first,
+        simulation.head_to_head(BotKind::Random, BotKind::Markov)
+    );
+
+    let summary = first.summary;
+    assert_eq!(summary.wins + summary.losses + summary.draws, 250);
+
+    let other = Simulation {
+        seed: 43,
+        ..simulation
+    };
+    assert_ne!(first, other.head_to_head(BotKind::Random, BotKind::Markov));
+}
+
+#[test]
+fn simulation_pairs_every_bot() {
+    let report = Simulation {
+        seed: 1,
+        tournaments: 1,
+        rounds: 10,
+    }
+    .run(&[]);
+    assert_eq!(report.guide_points, 0);
+    assert_eq!(report.against_guide.len(), BotKind::ALL.len());
+    assert_eq!(report.head_to_head.len(), 3);
+}
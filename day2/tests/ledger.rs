@@ -0,0 +1,54 @@
+use aoc_day2::{eval_tournament, parse_tournament, DecodeStrategy, Ledger, MatchResult, Summary};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+fn decode(strategy: DecodeStrategy) -> Ledger {
+    let rounds: Vec<&str> = SAMPLE.lines().collect();
+    Ledger::new(&parse_tournament(&rounds, strategy).unwrap())
+}
+
+#[test]
+fn running_totals_match_eval_tournament() {
+    let rounds: Vec<&str> = SAMPLE.lines().collect();
+    let moves = parse_tournament(&rounds, DecodeStrategy::Move).unwrap();
+    let ledger = Ledger::new(&moves);
+    assert_eq!(ledger.totals(), eval_tournament(&moves));
+
+    let user_totals: Vec<u32> = ledger.entries().iter().map(|e| e.user_total).collect();
+    // Rounds score 8, 1 and 6 in the puzzle text
+    assert_eq!(user_totals, vec![8, 9, 15]);
+    assert_eq!(ledger.entries()[1].result, MatchResult::Lose);
+    assert_eq!(ledger.entries()[1].opp_points, 8);
+}
+
+#[test]
+fn summary_counts_results() {
+    assert_eq!(
+        decode(DecodeStrategy::Move).summary(),
+        Summary { wins: 1, losses: 1, draws: 1 }
+    );
+    assert_eq!(
+        decode(DecodeStrategy::Outcome).summary().to_string(),
+        "1 won, 1 lost, 1 drawn"
+    );
+}
+
+#[test]
+fn strategies_diverge_in_the_first_round() {
+    let moves = decode(DecodeStrategy::Move);
+    let outcomes = decode(DecodeStrategy::Outcome);
+    assert_eq!(moves.first_divergence(&outcomes), Some(1));
+    assert_eq!(moves.first_divergence(&moves), None);
+}
+
+#[test]
+fn csv_export() {
+    let csv = decode(DecodeStrategy::Outcome).to_csv();
+    assert_eq!(
+        csv,
+        "round,opponent,user,result,opponent_points,user_points,opponent_total,user_total\n\
+         1,Rock,Rock,Draw,4,4,4,4\n\
+         2,Paper,Rock,Lose,8,1,12,5\n\
+         3,Scissors,Rock,Win,3,7,15,12\n"
+    );
+}
@@ -0,0 +1,139 @@
+use aoc_day2::{eval_round, MatchResult, Rules, Selection};
+
+const RPSLS: &str = include_str!("../rules/rock_paper_scissors_lizard_spock.toml");
+
+#[test]
+fn default_rules_score_the_puzzle() {
+    use Selection::*;
+    // Examples from the puzzle text, from the user's point of view
+    assert_eq!(eval_round(Rock, Paper), (1, 8));
+    assert_eq!(eval_round(Paper, Rock), (8, 1));
+    assert_eq!(eval_round(Scissors, Scissors), (6, 6));
+    assert_eq!(Rules::default(), *Rules::classic());
+}
+
+#[test]
+fn rpsls_file_matches_builtin() {
+    let rules = Rules::from_toml(RPSLS).unwrap();
+    assert_eq!(rules, Rules::rock_paper_scissors_lizard_spock());
+
+    let spock = rules.find("Spock").unwrap();
+    let lizard = rules.find("Lizard").unwrap();
+    let rock = rules.find("Rock").unwrap();
+    assert_eq!(rules.result(lizard, spock), MatchResult::Win);
+    assert_eq!(rules.result(rock, spock), MatchResult::Lose);
+    assert_eq!(rules.score(spock, lizard), (5, 10));
+    // Every move beats exactly two others
+    for mv in 0..rules.len() {
+        assert_eq!((0..rules.len()).filter(|&other| rules.beats(mv, other)).count(), 2);
+    }
+}
+
+#[test]
+fn custom_rules_default_to_the_puzzle_points() {
+    let rules = Rules::from_toml(
+        r#"
+        [[moves]]
+        name = "Fire"
+        points = 10
+        beats = ["Grass"]
+
+        [[moves]]
+        name = "Grass"
+        points = 20
+        beats = ["Water"]
+
+        [[moves]]
+        name = "Water"
+        points = 30
+        beats = ["Fire"]
+
+        [[moves]]
+        name = "Rest"
+        points = 0
+        "#,
+    )
+    .unwrap();
+    let (fire, water, rest) = (0, 2, 3);
+    assert_eq!(rules.score(fire, water), (10, 36));
+    assert_eq!(rules.result(rest, fire), MatchResult::Draw);
+    assert_eq!(rules.score_tournament(&[(fire, water), (rest, rest)]), (13, 39));
+}
+
+#[test]
+fn invalid_rules_are_rejected() {
+    let unknown = "[[moves]]\nname = \"Rock\"\npoints = 1\nbeats = [\"Paper\"]\n";
+    assert!(Rules::from_toml(unknown).is_err());
+    let itself = "[[moves]]\nname = \"Rock\"\npoints = 1\nbeats = [\"Rock\"]\n";
+    assert!(Rules::from_toml(itself).is_err());
+    let mutual = "[[moves]]\nname = \"A\"\npoints = 1\nbeats = [\"B\"]\n\
+                  [[moves]]\nname = \"B\"\npoints = 1\nbeats = [\"A\"]\n";
+    assert!(Rules::from_toml(mutual).is_err());
+    assert!(Rules::from_toml("moves = []").is_err());
+}
+
+#[test]
+fn tournaments_scored_with_loaded_rules() {
+    use aoc_day2::{eval_tournament, eval_tournament_with, Ledger, MoveDef, OutcomePoints};
+    use Selection::*;
+
+    let rounds = [(Rock, Paper), (Paper, Rock), (Scissors, Scissors)];
+    let classic = Rules::rock_paper_scissors();
+    assert_eq!(eval_tournament_with(&classic, &rounds).unwrap(), eval_tournament(&rounds));
+    assert_eq!(Ledger::with_rules(&rounds, &classic).unwrap(), Ledger::new(&rounds));
+
+    // Scissors beats everything, and draws score nothing
+    let outcome = OutcomePoints { win: 10, draw: 0, lose: 0 };
+    let rules = Rules::new(
+        outcome,
+        vec![
+            MoveDef::new("Rock", 1, &["Paper"]),
+            MoveDef::new("Paper", 1, &[]),
+            MoveDef::new("Scissors", 1, &["Rock", "Paper"]),
+        ],
+    )
+    .unwrap();
+    assert_eq!(eval_tournament_with(&rules, &rounds).unwrap(), (13, 13));
+    let ledger = Ledger::with_rules(&rounds, &rules).unwrap();
+    assert_eq!(ledger.totals(), (13, 13));
+    assert_eq!(ledger.entries()[0].result, MatchResult::Lose);
+
+    let too_few = Rules::new(OutcomePoints::default(), vec![MoveDef::new("Rock", 1, &[])]);
+    let too_few = too_few.unwrap();
+    assert!(eval_tournament_with(&too_few, &rounds).is_err());
+    assert!(Ledger::with_rules(&rounds, &too_few).is_err());
+}
+
+#[test]
+fn loaded_rules_find_selections_by_name() {
+    use aoc_day2::{eval_tournament, eval_tournament_with, Ledger, MoveDef, OutcomePoints};
+    use Selection::*;
+
+    let rounds = [(Rock, Paper), (Paper, Rock), (Scissors, Scissors), (Scissors, Rock)];
+    // The puzzle's rules with the moves listed in another order, after a move of their own
+    let reordered = Rules::new(
+        OutcomePoints::default(),
+        vec![
+            MoveDef::new("Well", 4, &["Rock", "Scissors"]),
+            MoveDef::new("Scissors", 3, &["Paper"]),
+            MoveDef::new("Rock", 1, &["Scissors"]),
+            MoveDef::new("Paper", 2, &["Rock", "Well"]),
+        ],
+    )
+    .unwrap();
+    assert_eq!(eval_tournament_with(&reordered, &rounds).unwrap(), eval_tournament(&rounds));
+    assert_eq!(Ledger::with_rules(&rounds, &reordered).unwrap(), Ledger::new(&rounds));
+
+    let no_paper = Rules::new(
+        OutcomePoints::default(),
+        vec![
+            MoveDef::new("Rock", 1, &["Scissors"]),
+            MoveDef::new("Scissors", 3, &["Spock"]),
+            MoveDef::new("Spock", 5, &["Rock"]),
+        ],
+    )
+    .unwrap();
+    let err = eval_tournament_with(&no_paper, &rounds).unwrap_err();
+    assert_eq!(err.to_string(), "Rules have no Paper move to score the guide with");
+    assert!(Ledger::with_rules(&rounds, &no_paper).is_err());
+}
@@ -0,0 +1,56 @@
+use aoc_common::Solution;
+use aoc_day2::{eval_tournament, parse_tournament, Day2, DecodeStrategy, Selection};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn sample_part_1() {
+    let guide = Day2::parse(SAMPLE).unwrap();
+    assert_eq!(Day2::part_1(&guide).unwrap(), 15);
+}
+
+#[test]
+fn sample_part_2() {
+    let guide = Day2::parse(SAMPLE).unwrap();
+    assert_eq!(Day2::part_2(&guide).unwrap(), 12);
+}
+
+#[test]
+fn invalid_choice_is_located() {
+    let error = Day2::parse("A Y\nB Q\n").unwrap_err();
+    assert_eq!((error.day, error.line, error.column), (2, 2, 3));
+    assert_eq!(error.text, "Q");
+}
+
+#[test]
+fn sample_with_each_decode_strategy() {
+    let rounds: Vec<&str> = SAMPLE.lines().collect();
+    let moves = parse_tournament(&rounds, DecodeStrategy::Move).unwrap();
+    let outcomes = parse_tournament(&rounds, DecodeStrategy::Outcome).unwrap();
+    assert_eq!(eval_tournament(&moves).1, 15);
+    assert_eq!(eval_tournament(&outcomes).1, 12);
+    // "A Y" is paper as a move but a draw, so rock, as an outcome
+    assert_eq!(moves[0], (Selection::Rock, Selection::Paper));
+    assert_eq!(outcomes[0], (Selection::Rock, Selection::Rock));
+}
+
+#[test]
+fn decode_outcomes() {
+    use Selection::*;
+    let decode = |column, opp| DecodeStrategy::Outcome.decode(column, opp);
+    assert_eq!(decode("X", Rock), Some(Scissors));
+    assert_eq!(decode("Z", Rock), Some(Paper));
+    assert_eq!(decode("X", Scissors), Some(Paper));
+    assert_eq!(decode("Y", Paper), Some(Paper));
+    assert_eq!(decode("W", Paper), None);
+    assert_eq!(DecodeStrategy::Move.decode("Z", Rock), Some(Scissors));
+}
+
+#[test]
+fn sample_saved_with_crlf_and_trailing_blank_lines() {
+    let input = format!("\u{feff}{}\r\n\r\n", SAMPLE.replace('\n', "\r\n"));
+    let expected = Day2::part_1(&Day2::parse(SAMPLE).unwrap()).unwrap();
+    let parsed = Day2::parse(&input).unwrap();
+    assert_eq!(Day2::part_1(&parsed).unwrap().to_string(), expected.to_string());
+    assert!(Day2::part_2(&parsed).is_ok());
+}
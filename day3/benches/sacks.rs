@@ -0,0 +1,33 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use aoc_day3::{find_badges, find_badges_hashset, process_sacks, process_sacks_hashset};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+// The sample repeated up to roughly the size of a puzzle input, 300 sacks
+fn sacks() -> Vec<&'static str> {
+    SAMPLE.lines().cycle().take(300).collect()
+}
+
+fn bench_sacks(c: &mut Criterion) {
+    let sacks = sacks();
+    assert_eq!(process_sacks(&sacks), process_sacks_hashset(&sacks));
+    assert_eq!(find_badges(&sacks), find_badges_hashset(&sacks));
+
+    let mut group = c.benchmark_group("process_sacks");
+    group.bench_function("bitmask", |b| b.iter(|| process_sacks(black_box(&sacks))));
+    group.bench_function("hashset", |b| {
+        b.iter(|| process_sacks_hashset(black_box(&sacks)))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("find_badges");
+    group.bench_function("bitmask", |b| b.iter(|| find_badges(black_box(&sacks))));
+    group.bench_function("hashset", |b| {
+        b.iter(|| find_badges_hashset(black_box(&sacks)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_sacks);
+criterion_main!(benches);
@@ -0,0 +1,140 @@
+use std::fmt::{Display, Formatter};
+
+use crate::{find_badges_with, PriorityMap};
+
+// Something wrong with a single sack
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    // The sack can't be split into two compartments of equal size, `length` is in items
+    OddLength { length: usize },
+    NoSharedItem,
+    // Every item in both compartments, lowest priority first
+    SharedItems { items: Vec<char> },
+    // An item that isn't in the alphabet, at its 1-based column
+    InvalidItem { column: usize, item: char },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub problem: Problem,
+}
+
+// Every problem found with the sacks, in line order, along with the sums worked out in spite of
+//      them. Invalid items are left out of the compartments and a sack with several shared items
+//      counts the lowest priority one, so `common_sum` matches `process_sacks` for sacks it would
+//      accept. `badge_sum` is `None` when `find_badges` fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    pub diagnostics: Vec<Diagnostic>,
+    pub common_sum: u32,
+    pub badge_sum: Option<u32>,
+}
+
+impl Diagnosis {
+    pub fn is_clean(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+}
+
+// Check every sack rather than stopping at the first bad one like `process_sacks`
+pub fn diagnose(sacks: &[&str]) -> Diagnosis {
+    diagnose_with(sacks, PriorityMap::standard())
+}
+
+pub fn diagnose_with(sacks: &[&str], priorities: &PriorityMap) -> Diagnosis {
+    let mut diagnostics = Vec::new();
+    let mut common_sum = 0;
+    for (i, sack) in sacks.iter().enumerate() {
+        let line = i + 1;
+        let mut report = |problem| diagnostics.push(Diagnostic { line, problem });
+        let length = sack.chars().count();
+        if !length.is_multiple_of(2) {
+            report(Problem::OddLength { length });
+        }
+
+        let half = length / 2;
+        let (mut first, mut second) = (0u64, 0u64);
+        for (index, item) in sack.chars().enumerate() {
+            let Some(bit) = priorities.bit(item) else {
+                report(Problem::InvalidItem {
+                    column: index + 1,
+                    item,
+                });
+                continue;
+            };
+            if index < half {
+                first |= bit;
+            } else {
+                second |= bit;
+            }
+        }
+
+        let shared = first & second;
+        match shared.count_ones() {
+            0 => report(Problem::NoSharedItem),
+            1 => {}
+            _ => report(Problem::SharedItems {
+                items: items(shared, priorities),
+            }),
+        }
+        if shared != 0 {
+            common_sum += shared.trailing_zeros();
+        }
+    }
+
+    Diagnosis {
+        diagnostics,
+        common_sum,
+        badge_sum: find_badges_with(sacks, priorities).ok(),
+    }
+}
+
+// The items in a bitmask from `item_set`, lowest priority first
+fn items(mut set: u64, priorities: &PriorityMap) -> Vec<char> {
+    let mut items = Vec::with_capacity(set.count_ones() as usize);
+    while set != 0 {
+        items.extend(priorities.item(set.trailing_zeros()));
+        set &= set - 1;
+    }
+    items
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::OddLength { length } => {
+                write!(f, "{} items can't be split into equal compartments", length)
+            }
+            Problem::NoSharedItem => write!(f, "No item is in both compartments"),
+            Problem::SharedItems { items } => write!(
+                f,
+                "{} items are in both compartments: {}",
+                items.len(),
+                items.iter().collect::<String>()
+            ),
+            Problem::InvalidItem { column, item } => {
+                write!(f, "{:?} at column {} isn't in the alphabet", item, column)
+            }
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Sack on line {}: {}", self.line, self.problem)
+    }
+}
+
+impl Display for Diagnosis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for diagnostic in &self.diagnostics {
+            writeln!(f, "{}", diagnostic)?;
+        }
+        writeln!(f, "Sum of common values: {}", self.common_sum)?;
+        match self.badge_sum {
+            Some(sum) => writeln!(f, "Sum of found badges: {}", sum),
+            None => writeln!(f, "Sacks can't be split into groups with one badge each"),
+        }
+    }
+}
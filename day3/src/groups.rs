@@ -0,0 +1,165 @@
+use std::num::NonZeroUsize;
+
+use aoc_common::ParseError;
+
+use crate::{item_set, PriorityMap, DAY};
+
+// Group size of the puzzle
+pub const GROUP_SIZE: NonZeroUsize = match NonZeroUsize::new(3) {
+    Some(size) => size,
+    None => unreachable!(),
+};
+
+// A group of sacks and the one item they all carry. Sacks are numbered by their line in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    pub sacks: Vec<usize>,
+    pub badge: char,
+    pub priority: u32,
+}
+
+// Sum of the badge priorities of consecutive groups of `size` sacks. Every group must be complete
+//      and carry exactly one badge, errors point at the first sack of the offending group.
+pub fn find_badges_in_groups(
+    sacks: &[&str],
+    size: NonZeroUsize,
+    priorities: &PriorityMap,
+) -> Result<u32, ParseError> {
+    let size = size.get();
+    let mut badge_sum = 0;
+    for (i, group) in sacks.chunks(size).enumerate() {
+        let line = i * size + 1;
+        if group.len() < size {
+            return Err(ParseError::line(
+                DAY,
+                line,
+                group[0],
+                format!("Group has {} of {} sacks", group.len(), size),
+            ));
+        }
+
+        let mut badges = u64::MAX;
+        for (j, sack) in group.iter().enumerate() {
+            badges &= item_set(line + j, sack, priorities)?;
+        }
+        match badges.count_ones() {
+            0 => {
+                return Err(ParseError::line(
+                    DAY,
+                    line,
+                    group[0],
+                    "Group of sacks has no badge",
+                ))
+            }
+            1 => badge_sum += badges.trailing_zeros(),
+            n => {
+                return Err(ParseError::line(
+                    DAY,
+                    line,
+                    group[0],
+                    format!("Group of sacks has {} badges", n),
+                ))
+            }
+        }
+    }
+    Ok(badge_sum)
+}
+
+// Split every sack in to groups of `size`, in any order, so each group carries exactly one badge.
+//      Sacks are grouped with the earliest ungrouped sack first, backtracking whenever a group
+//      can't be completed. `None` if there's no such grouping. The search is exponential in the
+//      worst case, but sacks that share nothing are ruled out straight away.
+pub fn search_groups(
+    sacks: &[&str],
+    size: NonZeroUsize,
+    priorities: &PriorityMap,
+) -> Result<Option<Vec<Group>>, ParseError> {
+    let sets = sacks
+        .iter()
+        .enumerate()
+        .map(|(i, sack)| item_set(i + 1, sack, priorities))
+        .collect::<Result<Vec<u64>, ParseError>>()?;
+    if sets.len() % size.get() != 0 {
+        return Ok(None);
+    }
+
+    let mut search = Search {
+        sets: &sets,
+        size: size.get(),
+        grouped: vec![false; sets.len()],
+        groups: Vec::with_capacity(sets.len() / size.get()),
+    };
+    if !search.next_group() {
+        return Ok(None);
+    }
+
+    let groups = search
+        .groups
+        .into_iter()
+        .map(|(sacks, badges)| {
+            let priority = badges.trailing_zeros();
+            Group {
+                sacks: sacks.iter().map(|i| i + 1).collect(),
+                // Every priority in a bitmask has an item
+                badge: priorities.item(priority).unwrap_or_default(),
+                priority,
+            }
+        })
+        .collect();
+    Ok(Some(groups))
+}
+
+struct Search<'a> {
+    sets: &'a [u64],
+    size: usize,
+    grouped: Vec<bool>,
+    // Index of each sack in the group along with the items they share
+    groups: Vec<(Vec<usize>, u64)>,
+}
+
+impl Search<'_> {
+    // Start a group with the first ungrouped sack, true once every sack is grouped
+    fn next_group(&mut self) -> bool {
+        let Some(first) = self.grouped.iter().position(|&grouped| !grouped) else {
+            return true;
+        };
+        self.grouped[first] = true;
+        let found = self.extend(&mut vec![first], self.sets[first]);
+        if !found {
+            self.grouped[first] = false;
+        }
+        found
+    }
+
+    // Add sacks after the last one in `group` until it's full, `shared` are the items carried by
+    //      every sack in the group so far
+    fn extend(&mut self, group: &mut Vec<usize>, shared: u64) -> bool {
+        if group.len() == self.size {
+            if shared.count_ones() != 1 {
+                return false;
+            }
+            self.groups.push((group.clone(), shared));
+            if self.next_group() {
+                return true;
+            }
+            self.groups.pop();
+            return false;
+        }
+
+        let from = group.last().map_or(0, |last| last + 1);
+        for sack in from..self.sets.len() {
+            let shared = shared & self.sets[sack];
+            if self.grouped[sack] || shared == 0 {
+                continue;
+            }
+            self.grouped[sack] = true;
+            group.push(sack);
+            if self.extend(group, shared) {
+                return true;
+            }
+            group.pop();
+            self.grouped[sack] = false;
+        }
+        false
+    }
+}
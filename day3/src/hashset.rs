@@ -0,0 +1,70 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::ParseError;
+
+use crate::DAY;
+
+// The original `HashSet` based versions of `process_sacks` and `find_badges`, kept as a reference
+//      for the bitmask versions to be checked and benchmarked against.
+
+pub fn process_sacks_hashset(sacks: &[&str]) -> Result<u32, ParseError> {
+    let mut sum: u32 = 0;
+    for (i, sack) in sacks.iter().enumerate() {
+        let half = sack.chars().count() / 2;
+        let middle = sack.char_indices().nth(half).map_or(sack.len(), |(j, _)| j);
+        let (first, second) = sack.split_at(middle);
+        let first_set: HashSet<char> = HashSet::from_iter(first.chars());
+        let second_set: HashSet<char> = HashSet::from_iter(second.chars());
+        let common = find_common_item(first_set, second_set)
+            .ok_or_else(|| ParseError::line(DAY, i + 1, sack, "No item is in both compartments"))?;
+        sum += item_value(i + 1, sack, common)?;
+    }
+
+    Ok(sum)
+}
+
+pub fn find_badges_hashset(sacks: &[&str]) -> Result<u32, ParseError> {
+    let groups = sacks.chunks(3);
+    let mut badge_sum = 0;
+    for (i, group) in groups.enumerate() {
+        let mut badges: HashMap<char, u32> = HashMap::new();
+        group.iter().for_each(|sack| {
+            let sack_set: HashSet<char> = HashSet::from_iter(sack.chars());
+            sack_set.iter().for_each(|item| {
+                let entry = badges.entry(*item).or_insert(0);
+                *entry += 1;
+            });
+        });
+        // Errors point at the first sack of the group
+        let line = i * 3 + 1;
+        let group_badge = badges
+            .iter()
+            .find(|&b| *b.1 == 3)
+            .ok_or_else(|| ParseError::line(DAY, line, group[0], "Group of sacks has no badge"))?;
+        badge_sum += item_value(line, group[0], *group_badge.0)?;
+    }
+    Ok(badge_sum)
+}
+
+// Find the item that is shared by two compartments in a sack
+fn find_common_item(first: HashSet<char>, second: HashSet<char>) -> Option<char> {
+    first.intersection(&second).next().copied()
+}
+
+fn item_value(line: usize, sack: &str, item: char) -> Result<u32, ParseError> {
+    get_value(item).ok_or_else(|| {
+        let column = sack.chars().position(|c| c == item).unwrap_or(0) + 1;
+        ParseError::new(DAY, line, column, item.to_string(), "Items must be letters")
+    })
+}
+
+// Convert a char in to a priority value (a-z = 1-26; A-Z = 27-52)
+fn get_value(item: char) -> Option<u32> {
+    if item.is_ascii_uppercase() {
+        Some(item as u32 - 38)
+    } else if item.is_ascii_lowercase() {
+        Some(item as u32 - 96)
+    } else {
+        None
+    }
+}
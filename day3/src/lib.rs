@@ -1,53 +1,114 @@
-use std::collections::{HashMap, HashSet};
+use aoc_common::{ParseError, Solution};
+use eyre::Result;
+
+mod diagnostics;
+mod groups;
+mod hashset;
+mod priority;
+mod repack;
+
+pub use diagnostics::{diagnose, diagnose_with, Diagnosis, Diagnostic, Problem};
+pub use groups::{find_badges_in_groups, search_groups, Group, GROUP_SIZE};
+// Only for the benches and tests to compare against, not part of the crate's API
+#[doc(hidden)]
+pub use hashset::{find_badges_hashset, process_sacks_hashset};
+pub use priority::{PriorityMap, MAX_ITEMS};
+pub use repack::{plan_repack, Plan, Swap, Swaps};
+
+const DAY: u8 = 3;
 
 // Split each sack into it's two compartments, find their common values, and return the sum of
-//      the priority of all found values.
-pub fn process_sacks(sacks: &[&str]) -> Result<u32, String> {
+//      the priority of all found values, using the puzzle's alphabet.
+pub fn process_sacks(sacks: &[&str]) -> Result<u32, ParseError> {
+    process_sacks_with(sacks, PriorityMap::standard())
+}
+
+// `process_sacks` with any alphabet. Each compartment is a bitmask of the priorities of its items,
+//      so finding the common item is a single AND. If several items are in both compartments the
+//      one with the lowest priority is counted.
+pub fn process_sacks_with(sacks: &[&str], priorities: &PriorityMap) -> Result<u32, ParseError> {
     let mut sum: u32 = 0;
-    sacks.iter().for_each(|sack| {
-        let (first, second) = sack.split_at(sack.len() / 2);
-        let first_set: HashSet<char> = HashSet::from_iter(first.chars());
-        let second_set: HashSet<char> = HashSet::from_iter(second.chars());
-        let common = find_common_item(first_set, second_set).unwrap();
-        sum += get_value(common);
-    });
+    for (i, sack) in sacks.iter().enumerate() {
+        let half = sack.chars().count() / 2;
+        let (mut first, mut second) = (0u64, 0u64);
+        for (j, item) in sack.chars().enumerate() {
+            let bit = priorities.bit(item).ok_or_else(|| invalid_item(i + 1, j + 1, item))?;
+            if j < half {
+                first |= bit;
+            } else {
+                second |= bit;
+            }
+        }
+        let common = first & second;
+        if common == 0 {
+            return Err(ParseError::line(DAY, i + 1, sack, "No item is in both compartments"));
+        }
+        sum += common.trailing_zeros();
+    }
 
     Ok(sum)
 }
 
 // Find every group badge in all of the sacks (one group is a set of 3 sacks) and return the sum
-//      of the priority of all badges
-pub fn find_badges(sacks: &[&str]) -> Result<u32, String> {
-    let groups = sacks.chunks(3);
-    let mut badge_sum = 0;
-    groups.for_each(|group| {
-        let mut badges: HashMap<char, u32> = HashMap::new();
-        group.iter().for_each(|sack| {
-            let sack_set: HashSet<char> = HashSet::from_iter(sack.chars());
-            sack_set.iter().for_each(|item| {
-                let entry = badges.entry(*item).or_insert(0);
-                *entry += 1;
-            });
-        });
-        let group_badge = badges.iter().find(|&b| *b.1 == 3).unwrap();
-        badge_sum += get_value(*group_badge.0);
-    });
-    Ok(badge_sum)
+//      of the priority of all badges, using the puzzle's alphabet. See `find_badges_in_groups` for
+//      other group sizes.
+pub fn find_badges(sacks: &[&str]) -> Result<u32, ParseError> {
+    find_badges_with(sacks, PriorityMap::standard())
 }
 
-// Find the item that is shared by two compartments in a sack
-fn find_common_item(first: HashSet<char>, second: HashSet<char>) -> Result<char, String> {
-    if let Some(common) = first.intersection(&second).next() {
-        return Ok(*common)
-    }
-    Err(String::from("No common item found!"))
+pub fn find_badges_with(sacks: &[&str], priorities: &PriorityMap) -> Result<u32, ParseError> {
+    find_badges_in_groups(sacks, GROUP_SIZE, priorities)
+}
+
+// Bitmask of every item in a sack, bit `n` is set if there's an item with priority `n`
+fn item_set(line: usize, sack: &str, priorities: &PriorityMap) -> Result<u64, ParseError> {
+    sack.chars().enumerate().try_fold(0, |set, (i, item)| {
+        Ok(set | priorities.bit(item).ok_or_else(|| invalid_item(line, i + 1, item))?)
+    })
+}
+
+fn invalid_item(line: usize, column: usize, item: char) -> ParseError {
+    ParseError::new(DAY, line, column, item.to_string(), "Item isn't in the alphabet")
 }
 
-// Convert a char in to a priority value (a-z = 1-26; A-Z = 27-52)
-fn get_value(item: char) -> u32 {
-    if item.is_uppercase() {
-        item as u32 - 38
-    } else {
-        item as u32 - 96
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = DAY;
+
+    type Parsed = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let input = aoc_common::normalize(input);
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_1(sacks: &Self::Parsed) -> Result<Self::Answer1> {
+        let sacks: Vec<&str> = sacks.iter().map(String::as_str).collect();
+        Ok(process_sacks(&sacks)?)
     }
-}
\ No newline at end of file
+
+    fn part_2(sacks: &Self::Parsed) -> Result<Self::Answer2> {
+        let sacks: Vec<&str> = sacks.iter().map(String::as_str).collect();
+        Ok(find_badges(&sacks)?)
+    }
+
+    fn warnings(sacks: &Self::Parsed) -> Vec<String> {
+        let sacks: Vec<&str> = sacks.iter().map(String::as_str).collect();
+        let mut warnings: Vec<String> = diagnose(&sacks)
+            .diagnostics
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        if !sacks.len().is_multiple_of(GROUP_SIZE.get()) {
+            warnings.push(format!(
+                "{} sacks can't be split into groups of {}",
+                sacks.len(),
+                GROUP_SIZE
+            ));
+        }
+        warnings
+    }
+}
@@ -1,21 +1,39 @@
 use std::error::Error;
-use std::fs;
 
 use aoc_day3 as AoC;
 
 fn main() -> Result<(), Box<dyn Error>> {
-        use std::time::Instant;
-        let now = Instant::now();
-        {
-                let input = fs::read_to_string("day3/day3.txt")?;
-                let lines: Vec<&str> = input.lines().collect();
-                let common_sum = AoC::process_sacks(&lines)?;
-                println!("Sum of common values: {}", common_sum);
-                let badge_sum = AoC::find_badges(&lines)?;
-                println!("Sum of found badges: {}", badge_sum);
+    let input = aoc_common::read_input("day3/day3.txt")?;
+    let lines: Vec<&str> = input.lines().collect();
+    for diagnostic in AoC::diagnose(&lines).diagnostics {
+        println!("{}", diagnostic);
+    }
+    let common_sum = AoC::process_sacks(&lines)?;
+    println!("Sum of common values: {}", common_sum);
+    let badge_sum = AoC::find_badges(&lines)?;
+    println!("Sum of found badges: {}", badge_sum);
+    match AoC::search_groups(&lines, AoC::GROUP_SIZE, AoC::PriorityMap::standard())? {
+        Some(groups) => {
+            let sum: u32 = groups.iter().map(|group| group.priority).sum();
+            println!("Sum of badges of searched groups: {}", sum);
         }
-        let elapsed = now.elapsed();
-        println!("Elapsed: {:.2?}", elapsed);
+        None => println!("No grouping gives every group a single badge"),
+    }
+    let plan = AoC::plan_repack(
+        &lines,
+        AoC::Swaps::Groups(AoC::GROUP_SIZE),
+        AoC::PriorityMap::standard(),
+    )?;
+    println!(
+        "Swaps to repack: {}{} ({} sacks can't be fixed)",
+        plan.swaps.len(),
+        if plan.optimal {
+            ""
+        } else {
+            ", not proven to be the fewest"
+        },
+        plan.unfixable.len()
+    );
 
     Ok(())
 }
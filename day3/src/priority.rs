@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use eyre::{eyre, Result, WrapErr};
+use serde::Deserialize;
+
+// Sacks are handled as bitmasks of item priorities in a `u64`, so priorities run from 1 to 63
+pub const MAX_ITEMS: usize = 63;
+
+#[derive(Debug, Deserialize)]
+struct AlphabetFile {
+    alphabet: String,
+}
+
+// The items that can be packed in a sack and their priorities. Items are given in priority order,
+//      the first has priority 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriorityMap {
+    // Item with each priority, priority 1 is at index 0
+    items: Vec<char>,
+    // Priority of each ASCII item, 0 if it's not in the alphabet, so the puzzle's alphabet never
+    //      needs a hash lookup
+    ascii: [u8; 128],
+    other: HashMap<char, u8>,
+}
+
+impl PriorityMap {
+    // Whitespace is ignored so long alphabets can be split over several lines
+    pub fn new(alphabet: &str) -> Result<Self> {
+        let items: Vec<char> = alphabet.chars().filter(|c| !c.is_whitespace()).collect();
+        if items.is_empty() {
+            return Err(eyre!("An alphabet needs at least one item"));
+        }
+        if items.len() > MAX_ITEMS {
+            return Err(eyre!(
+                "An alphabet can have at most {} items, not {}",
+                MAX_ITEMS,
+                items.len()
+            ));
+        }
+
+        let mut ascii = [0; 128];
+        let mut other = HashMap::new();
+        for (i, &item) in items.iter().enumerate() {
+            let priority = i as u8 + 1;
+            let previous = match usize::try_from(u32::from(item)) {
+                Ok(code) if code < ascii.len() => std::mem::replace(&mut ascii[code], priority),
+                _ => other.insert(item, priority).unwrap_or(0),
+            };
+            if previous != 0 {
+                return Err(eyre!("{:?} is in the alphabet more than once", item));
+            }
+        }
+        Ok(PriorityMap {
+            items,
+            ascii,
+            other,
+        })
+    }
+
+    // An alphabet in the format of `alphabets/greek.toml`
+    pub fn from_toml(config: &str) -> Result<Self> {
+        let file: AlphabetFile = toml::from_str(config).wrap_err("Failed to parse alphabet")?;
+        PriorityMap::new(&file.alphabet)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let config = aoc_common::read_input(path)?;
+        PriorityMap::from_toml(&config)
+            .wrap_err_with(|| format!("Invalid alphabet in {}", path.display()))
+    }
+
+    // The puzzle's alphabet, a-z = 1-26; A-Z = 27-52
+    pub fn letters() -> Self {
+        let alphabet: String = ('a'..='z').chain('A'..='Z').collect();
+        PriorityMap::new(&alphabet).expect("The puzzle's alphabet is valid")
+    }
+
+    // Shared copy of the puzzle's alphabet, used by the functions that don't take one
+    pub fn standard() -> &'static PriorityMap {
+        static STANDARD: OnceLock<PriorityMap> = OnceLock::new();
+        STANDARD.get_or_init(PriorityMap::letters)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn priority(&self, item: char) -> Option<u32> {
+        let priority = match usize::try_from(u32::from(item)) {
+            Ok(code) if code < self.ascii.len() => self.ascii[code],
+            _ => self.other.get(&item).copied().unwrap_or(0),
+        };
+        (priority != 0).then_some(u32::from(priority))
+    }
+
+    pub fn item(&self, priority: u32) -> Option<char> {
+        let index = usize::try_from(priority).ok()?.checked_sub(1)?;
+        self.items.get(index).copied()
+    }
+
+    // The item as a bit at its priority in a sack's bitmask
+    pub(crate) fn bit(&self, item: char) -> Option<u64> {
+        self.priority(item).map(|priority| 1 << priority)
+    }
+}
+
+impl Default for PriorityMap {
+    fn default() -> Self {
+        PriorityMap::letters()
+    }
+}
@@ -0,0 +1,426 @@
+use std::collections::HashMap;
+use std::iter;
+use std::mem;
+use std::num::NonZeroUsize;
+
+use aoc_common::ParseError;
+
+use crate::{find_badges_with, invalid_item, PriorityMap, MAX_ITEMS};
+
+// Which swaps the planner may use
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Swaps {
+    // Only swap items between the two compartments of a sack
+    Compartments,
+    // Also trade items between sacks in consecutive groups of this size
+    Groups(NonZeroUsize),
+}
+
+// A single swap of two items. Sacks are numbered by their line in the input and compartments
+//      from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Swap {
+    // `first` out of the first compartment of `sack` for `second` out of its second
+    Compartments {
+        sack: usize,
+        first: char,
+        second: char,
+    },
+    // `item` out of a compartment of `sack` for `other_item` out of a compartment of `other`
+    Sacks {
+        sack: usize,
+        compartment: usize,
+        item: char,
+        other: usize,
+        other_compartment: usize,
+        other_item: char,
+    },
+}
+
+// Swaps that leave the compartments of every sack sharing exactly one item, in the order to make
+//      them, along with the repacked sacks and their priority sums
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub swaps: Vec<Swap>,
+    pub sacks: Vec<String>,
+    // Sacks that no swaps can fix, they're left as they were
+    pub unfixable: Vec<usize>,
+    // Sum of the shared item of every fixed sack
+    pub common_sum: u32,
+    // `find_badges_with` on the repacked sacks, `None` if it fails
+    pub badge_sum: Option<u32>,
+    // Whether `swaps` is known to be the fewest possible. Always so for swaps between
+    //      compartments, for trades only when no group needed any.
+    pub optimal: bool,
+}
+
+// Items of each compartment of a sack by priority
+type Sack = [Vec<usize>; 2];
+
+// Plan the swaps for every sack. Swaps between the compartments of a sack are always the fewest
+//      possible. Trades between sacks are found greedily, the group makes whichever single trade
+//      most improves it until none do, preferring to fix a sack over saving swaps, so plans using
+//      them aren't guaranteed to be the shortest, see `Plan::optimal`.
+pub fn plan_repack(
+    sacks: &[&str],
+    swaps: Swaps,
+    priorities: &PriorityMap,
+) -> Result<Plan, ParseError> {
+    let mut packed = sacks
+        .iter()
+        .enumerate()
+        .map(|(i, sack)| parse_sack(i + 1, sack, priorities))
+        .collect::<Result<Vec<Sack>, ParseError>>()?;
+
+    let mut plan = Plan {
+        swaps: Vec::new(),
+        sacks: Vec::new(),
+        unfixable: Vec::new(),
+        common_sum: 0,
+        badge_sum: None,
+        optimal: true,
+    };
+    if let Swaps::Groups(size) = swaps {
+        for (i, group) in packed.chunks_mut(size.get()).enumerate() {
+            plan.optimal &= trade(group, i * size.get(), priorities, &mut plan.swaps);
+        }
+    }
+
+    for (i, sack) in packed.iter_mut().enumerate() {
+        let Some(sack_swaps) = plan_sack(sack) else {
+            plan.unfixable.push(i + 1);
+            continue;
+        };
+        for (first, second) in sack_swaps {
+            swap_item(&mut sack[0], first, second);
+            swap_item(&mut sack[1], second, first);
+            plan.swaps.push(Swap::Compartments {
+                sack: i + 1,
+                first: item_char(priorities, first),
+                second: item_char(priorities, second),
+            });
+        }
+        plan.common_sum += sack[0]
+            .iter()
+            .find(|&priority| sack[1].contains(priority))
+            .map_or(0, |&priority| priority as u32);
+    }
+
+    plan.sacks = packed
+        .iter()
+        .map(|sack| {
+            sack.iter()
+                .flatten()
+                .map(|&p| item_char(priorities, p))
+                .collect()
+        })
+        .collect();
+    let repacked: Vec<&str> = plan.sacks.iter().map(String::as_str).collect();
+    plan.badge_sum = find_badges_with(&repacked, priorities).ok();
+    Ok(plan)
+}
+
+fn parse_sack(line: usize, sack: &str, priorities: &PriorityMap) -> Result<Sack, ParseError> {
+    let items = sack
+        .chars()
+        .enumerate()
+        .map(|(i, item)| {
+            let priority = priorities.priority(item);
+            priority
+                .map(|p| p as usize)
+                .ok_or_else(|| invalid_item(line, i + 1, item))
+        })
+        .collect::<Result<Vec<usize>, ParseError>>()?;
+    let (first, second) = items.split_at(items.len() / 2);
+    Ok([first.to_vec(), second.to_vec()])
+}
+
+// Every priority in a sack comes from `priorities`
+fn item_char(priorities: &PriorityMap, priority: usize) -> char {
+    priorities.item(priority as u32).unwrap_or_default()
+}
+
+fn swap_item(compartment: &mut [usize], from: usize, to: usize) {
+    if let Some(item) = compartment.iter_mut().find(|item| **item == from) {
+        *item = to;
+    }
+}
+
+// The fewest swaps between the compartments of a sack, as (item out of the first, item out of the
+//      second), that leave them sharing exactly one item. `None` if no arrangement does.
+//
+// Every item type ends up either only in the first compartment, only in the second, or for one
+//      type split between them. A swap moves an item each way, so the swaps needed are the items
+//      moved out of the first compartment. A knapsack over the item types, filling the first
+//      compartment back to its size, finds the cheapest arrangement.
+fn plan_sack(sack: &Sack) -> Option<Vec<(usize, usize)>> {
+    let [first, second] = count_items(sack);
+    let types: Vec<usize> = (1..=MAX_ITEMS)
+        .filter(|&t| first[t] + second[t] > 0)
+        .collect();
+    let size = sack[0].len();
+
+    // `fewest[i]` once the first `i` types are placed
+    let mut fewest = vec![start_placing(size)];
+    for &t in &types {
+        let previous = fewest.last()?;
+        let mut next = previous.clone();
+        place_type(previous, &mut next, first[t], second[t]);
+        fewest.push(next);
+    }
+    if fewest.last()?[1][size] == UNREACHABLE {
+        return None;
+    }
+
+    // Walk back through the table for how many of each type stay in the first compartment
+    let mut kept_counts = vec![0; types.len()];
+    let (mut shared, mut kept) = (1, size);
+    for (i, &t) in types.iter().enumerate().rev() {
+        let moved = fewest[i + 1][shared][kept];
+        let (count, split) = placements(first[t], second[t]).find(|&(count, split)| {
+            let (Some(shared), Some(kept)) = (
+                shared.checked_sub(usize::from(split)),
+                kept.checked_sub(count),
+            ) else {
+                return false;
+            };
+            let previous = fewest[i][shared][kept];
+            previous != UNREACHABLE && previous + first[t].saturating_sub(count) == moved
+        })?;
+        kept_counts[i] = count;
+        shared -= usize::from(split);
+        kept -= count;
+    }
+
+    let out = types
+        .iter()
+        .zip(&kept_counts)
+        .flat_map(|(&t, &count)| iter::repeat_n(t, first[t].saturating_sub(count)));
+    let back = types
+        .iter()
+        .zip(&kept_counts)
+        .flat_map(|(&t, &count)| iter::repeat_n(t, count.saturating_sub(first[t])));
+    Some(out.zip(back).collect())
+}
+
+// Items of each compartment of a sack by priority, counted
+type Counts = [[usize; MAX_ITEMS + 1]; 2];
+
+fn count_items(sack: &Sack) -> Counts {
+    let mut counts = [[0; MAX_ITEMS + 1]; 2];
+    for (compartment, items) in sack.iter().enumerate() {
+        for &item in items {
+            counts[compartment][item] += 1;
+        }
+    }
+    counts
+}
+
+// `fewest[shared][kept]` is the fewest items moved out of the first compartment placing the types
+//      so far with `kept` items in the first compartment and `shared` types split
+const UNREACHABLE: usize = usize::MAX;
+
+fn start_placing(size: usize) -> [Vec<usize>; 2] {
+    let mut fewest = [vec![UNREACHABLE; size + 1], vec![UNREACHABLE; size + 1]];
+    fewest[0][0] = 0;
+    fewest
+}
+
+// Place one more type into `next`, with `first` and `second` of its items in each compartment.
+//      Tries every way `placements` lists, as plain loops since this runs for every candidate trade.
+fn place_type(previous: &[Vec<usize>; 2], next: &mut [Vec<usize>; 2], first: usize, second: usize) {
+    let size = previous[0].len() - 1;
+    let total = first + second;
+    for row in next.iter_mut() {
+        row.fill(UNREACHABLE);
+    }
+    for (shared, row) in previous.iter().enumerate() {
+        for (kept, &moved) in row.iter().enumerate() {
+            if moved == UNREACHABLE {
+                continue;
+            }
+            let out = &mut next[shared];
+            out[kept] = out[kept].min(moved + first);
+            if kept + total <= size {
+                out[kept + total] = out[kept + total].min(moved);
+            }
+            if shared == 0 {
+                for count in (1..total).take_while(|&count| kept + count <= size) {
+                    let moved = moved + first.saturating_sub(count);
+                    next[1][kept + count] = next[1][kept + count].min(moved);
+                }
+            }
+        }
+    }
+}
+
+// The number of swaps `plan_sack` makes, without working out which they are
+fn fewest_swaps(counts: &Counts, size: usize) -> Option<usize> {
+    let [first, second] = counts;
+    let mut fewest = start_placing(size);
+    let mut next = fewest.clone();
+    for t in (1..=MAX_ITEMS).filter(|&t| first[t] + second[t] > 0) {
+        place_type(&fewest, &mut next, first[t], second[t]);
+        mem::swap(&mut fewest, &mut next);
+    }
+    Some(fewest[1][size]).filter(|&moved| moved != UNREACHABLE)
+}
+
+// Ways to place the items of one type as (items in the first compartment, whether it's split
+//      between both). Keeping them where they are comes first when that's possible.
+fn placements(first: usize, second: usize) -> impl Iterator<Item = (usize, bool)> {
+    let total = first + second;
+    iter::once((first, first > 0 && second > 0))
+        .chain([(total, false), (0, false)])
+        .chain((1..total).map(|count| (count, true)))
+}
+
+// A trade between two sacks of a group, by index in the group and priority
+struct Trade {
+    sack: usize,
+    compartment: usize,
+    item: usize,
+    other: usize,
+    other_compartment: usize,
+    other_item: usize,
+}
+
+// `fewest_swaps` for a sack of a group as it is, and after trading away an item of one of its
+//      compartments for another, so trades only re-plan the sacks they touch. Built afresh
+//      whenever the sack changes.
+struct SackCosts {
+    counts: Counts,
+    size: usize,
+    current: Option<usize>,
+    // By (compartment, item traded away, item received)
+    traded: HashMap<(usize, usize, usize), Option<usize>>,
+}
+
+impl SackCosts {
+    fn new(sack: &Sack) -> Self {
+        let counts = count_items(sack);
+        let size = sack[0].len();
+        SackCosts {
+            counts,
+            size,
+            current: fewest_swaps(&counts, size),
+            traded: HashMap::new(),
+        }
+    }
+
+    fn traded(&mut self, compartment: usize, item: usize, received: usize) -> Option<usize> {
+        let (counts, size) = (&self.counts, self.size);
+        *self
+            .traded
+            .entry((compartment, item, received))
+            .or_insert_with(|| {
+                let mut counts = *counts;
+                counts[compartment][item] -= 1;
+                counts[compartment][received] += 1;
+                fewest_swaps(&counts, size)
+            })
+    }
+}
+
+// (unfixable sacks, swaps) for a group from the cost of each sack, lower is better
+fn score(costs: impl Iterator<Item = Option<usize>>) -> (usize, usize) {
+    costs.fold((0, 0), |(unfixable, swaps), cost| match cost {
+        Some(cost) => (unfixable, swaps + cost),
+        None => (unfixable + 1, swaps),
+    })
+}
+
+// Make trades between the sacks of a group while one improves it, `offset` is the index of the
+//      group's first sack. Returns whether the group's swaps are known to be the fewest possible,
+//      which is only when it needs no trades at all.
+fn trade(
+    group: &mut [Sack],
+    offset: usize,
+    priorities: &PriorityMap,
+    swaps: &mut Vec<Swap>,
+) -> bool {
+    let mut costs: Vec<SackCosts> = group.iter().map(SackCosts::new).collect();
+    let mut traded = false;
+    loop {
+        let current = score(costs.iter().map(|costs| costs.current));
+        // A trade is a swap itself, so the group's compartment swaps would need to drop by two
+        if current.0 == 0 && current.1 < 2 {
+            return !traded;
+        }
+
+        let mut best: Option<((usize, usize), Trade)> = None;
+        for sack in 0..group.len() {
+            for other in sack + 1..group.len() {
+                for compartment in 0..2 {
+                    for other_compartment in 0..2 {
+                        let mut items = group[sack][compartment].clone();
+                        items.sort_unstable();
+                        items.dedup();
+                        let mut other_items = group[other][other_compartment].clone();
+                        other_items.sort_unstable();
+                        other_items.dedup();
+                        for &item in &items {
+                            for &other_item in other_items.iter().filter(|&&o| o != item) {
+                                let sack_cost = costs[sack].traded(compartment, item, other_item);
+                                let other_cost =
+                                    costs[other].traded(other_compartment, other_item, item);
+                                let (unfixable, trial_swaps) =
+                                    score(costs.iter().enumerate().map(|(i, costs)| {
+                                        if i == sack {
+                                            sack_cost
+                                        } else if i == other {
+                                            other_cost
+                                        } else {
+                                            costs.current
+                                        }
+                                    }));
+                                let trial_score = (unfixable, trial_swaps + 1);
+                                if trial_score < current
+                                    && best
+                                        .as_ref()
+                                        .is_none_or(|(best_score, _)| trial_score < *best_score)
+                                {
+                                    let trade = Trade {
+                                        sack,
+                                        compartment,
+                                        item,
+                                        other,
+                                        other_compartment,
+                                        other_item,
+                                    };
+                                    best = Some((trial_score, trade));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Several trades together might still help where no single one does
+        let Some((_, trade)) = best else {
+            return false;
+        };
+        traded = true;
+        swap_item(
+            &mut group[trade.sack][trade.compartment],
+            trade.item,
+            trade.other_item,
+        );
+        swap_item(
+            &mut group[trade.other][trade.other_compartment],
+            trade.other_item,
+            trade.item,
+        );
+        costs[trade.sack] = SackCosts::new(&group[trade.sack]);
+        costs[trade.other] = SackCosts::new(&group[trade.other]);
+        swaps.push(Swap::Sacks {
+            sack: offset + trade.sack + 1,
+            compartment: trade.compartment + 1,
+            item: item_char(priorities, trade.item),
+            other: offset + trade.other + 1,
+            other_compartment: trade.other_compartment + 1,
+            other_item: item_char(priorities, trade.other_item),
+        });
+    }
+}
@@ -0,0 +1,57 @@
+use aoc_day3::{find_badges, find_badges_hashset, process_sacks, process_sacks_hashset};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn matches_hashset_version() {
+    let sacks: Vec<&str> = SAMPLE.lines().collect();
+    assert_eq!(process_sacks(&sacks), process_sacks_hashset(&sacks));
+    assert_eq!(find_badges(&sacks), find_badges_hashset(&sacks));
+}
+
+#[test]
+fn every_priority() {
+    // Each letter shared between the compartments of its own sack
+    let sacks: Vec<String> = ('a'..='z')
+        .chain('A'..='Z')
+        .map(|item| format!("{}{}", item, item))
+        .collect();
+    let sacks: Vec<&str> = sacks.iter().map(String::as_str).collect();
+    assert_eq!(process_sacks(&sacks).unwrap(), (1..=52).sum());
+}
+
+#[test]
+fn no_common_item() {
+    let err = process_sacks(&["vJrwpWtwJgWr", "abcd"]).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 1));
+    assert_eq!(err.reason, "No item is in both compartments");
+}
+
+#[test]
+fn invalid_item() {
+    let err = process_sacks(&["ab1b"]).unwrap_err();
+    assert_eq!((err.line, err.column), (1, 3));
+    assert_eq!(err.text, "1");
+
+    // Points at the sack with the bad item, not the first sack of the group
+    let err = find_badges(&["abc", "aé", "a"]).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 2));
+    assert_eq!(err.text, "é");
+}
+
+#[test]
+fn multibyte_item_at_the_middle() {
+    // Split by bytes the middle would fall inside the 'é'
+    let err = process_sacks_hashset(&["aébb"]).unwrap_err();
+    assert_eq!(err.reason, "No item is in both compartments");
+    let err = process_sacks_hashset(&["aébé"]).unwrap_err();
+    assert_eq!((err.line, err.column), (1, 2));
+    assert_eq!(err.text, "é");
+}
+
+#[test]
+fn incomplete_group() {
+    let err = find_badges(&["aa", "ab", "ac", "ad", "ae"]).unwrap_err();
+    assert_eq!(err.line, 4);
+    assert_eq!(err.reason, "Group has 2 of 3 sacks");
+}
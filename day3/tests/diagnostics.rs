@@ -0,0 +1,95 @@
+use aoc_common::Solution;
+use aoc_day3::{diagnose, process_sacks, Day3, Diagnostic, Problem};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn sample_is_clean() {
+    let sacks: Vec<&str> = SAMPLE.lines().collect();
+    let diagnosis = diagnose(&sacks);
+    assert!(diagnosis.is_clean());
+    assert_eq!(diagnosis.common_sum, 157);
+    assert_eq!(diagnosis.badge_sum, Some(70));
+}
+
+#[test]
+fn reports_every_problem() {
+    let sacks = ["abcdb", "abcd", "abab", "a1c-ab", "vJrwpWtwJgWrhcsFMMfFFhFp"];
+    let diagnosis = diagnose(&sacks);
+    assert_eq!(
+        diagnosis.diagnostics,
+        vec![
+            Diagnostic {
+                line: 1,
+                problem: Problem::OddLength { length: 5 }
+            },
+            Diagnostic {
+                line: 2,
+                problem: Problem::NoSharedItem
+            },
+            Diagnostic {
+                line: 3,
+                problem: Problem::SharedItems {
+                    items: vec!['a', 'b']
+                }
+            },
+            Diagnostic {
+                line: 4,
+                problem: Problem::InvalidItem {
+                    column: 2,
+                    item: '1'
+                }
+            },
+            Diagnostic {
+                line: 4,
+                problem: Problem::InvalidItem {
+                    column: 4,
+                    item: '-'
+                }
+            },
+        ]
+    );
+    // Lowest shared priorities, b for line 1 (ab / cdb), a for 3 and 4 and p for 5
+    assert_eq!(diagnosis.common_sum, 2 + 1 + 1 + 16);
+    assert_eq!(diagnosis.badge_sum, None);
+}
+
+#[test]
+fn common_sum_matches_process_sacks() {
+    let sacks = ["abab", "bcbc", "bdbd"];
+    let diagnosis = diagnose(&sacks);
+    assert_eq!(diagnosis.common_sum, process_sacks(&sacks).unwrap());
+    assert_eq!(diagnosis.badge_sum, Some(2));
+}
+
+#[test]
+fn columns_count_chars() {
+    let diagnosis = diagnose(&["éaéa"]);
+    let columns: Vec<&Problem> = diagnosis.diagnostics.iter().map(|d| &d.problem).collect();
+    assert_eq!(
+        columns,
+        vec![
+            &Problem::InvalidItem {
+                column: 1,
+                item: 'é'
+            },
+            &Problem::InvalidItem {
+                column: 3,
+                item: 'é'
+            },
+        ]
+    );
+}
+
+#[test]
+fn warnings_come_from_diagnostics() {
+    let sacks = Day3::parse("abcdb\nabcd\n").unwrap();
+    assert_eq!(
+        Day3::warnings(&sacks),
+        vec![
+            "Sack on line 1: 5 items can't be split into equal compartments",
+            "Sack on line 2: No item is in both compartments",
+            "2 sacks can't be split into groups of 3",
+        ]
+    );
+}
@@ -0,0 +1,106 @@
+use std::num::NonZeroUsize;
+
+use aoc_day3::{find_badges, find_badges_in_groups, search_groups, Group, PriorityMap, GROUP_SIZE};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+fn size(n: usize) -> NonZeroUsize {
+    NonZeroUsize::new(n).unwrap()
+}
+
+fn letters() -> &'static PriorityMap {
+    PriorityMap::standard()
+}
+
+#[test]
+fn group_size_of_three_matches_find_badges() {
+    let sacks: Vec<&str> = SAMPLE.lines().collect();
+    assert_eq!(
+        find_badges_in_groups(&sacks, GROUP_SIZE, letters()),
+        find_badges(&sacks)
+    );
+}
+
+#[test]
+fn other_group_sizes() {
+    // No item is in all six sacks
+    let sacks: Vec<&str> = SAMPLE.lines().collect();
+    let err = find_badges_in_groups(&sacks, size(6), letters()).unwrap_err();
+    assert_eq!(err.reason, "Group of sacks has no badge");
+
+    assert_eq!(
+        find_badges_in_groups(&["ab", "bc"], size(2), letters()).unwrap(),
+        2
+    );
+    assert_eq!(
+        find_badges_in_groups(&["a", "bb", "cC"], size(1), letters())
+            .unwrap_err()
+            .line,
+        3
+    );
+}
+
+#[test]
+fn multiple_badges() {
+    let err = find_badges_in_groups(&["ab", "ab", "xy", "xz"], size(2), letters()).unwrap_err();
+    assert_eq!(err.line, 1);
+    assert_eq!(err.reason, "Group of sacks has 2 badges");
+}
+
+#[test]
+fn incomplete_trailing_group() {
+    let err = find_badges_in_groups(&["ab", "bc", "cd"], size(2), letters()).unwrap_err();
+    assert_eq!(err.line, 3);
+    assert_eq!(err.reason, "Group has 1 of 2 sacks");
+}
+
+#[test]
+fn search_finds_input_grouping() {
+    let sacks: Vec<&str> = SAMPLE.lines().collect();
+    let groups = search_groups(&sacks, GROUP_SIZE, letters())
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        groups,
+        vec![
+            Group {
+                sacks: vec![1, 2, 3],
+                badge: 'r',
+                priority: 18
+            },
+            Group {
+                sacks: vec![4, 5, 6],
+                badge: 'Z',
+                priority: 52
+            },
+        ]
+    );
+}
+
+#[test]
+fn search_regroups_sacks() {
+    // In order the first pair shares three items, but each can be paired with one of the others
+    let sacks = ["abx", "abx", "ac", "bd"];
+    assert!(find_badges_in_groups(&sacks, size(2), letters()).is_err());
+    let groups = search_groups(&sacks, size(2), letters()).unwrap().unwrap();
+    let grouped: Vec<(Vec<usize>, char)> = groups.into_iter().map(|g| (g.sacks, g.badge)).collect();
+    assert_eq!(grouped, vec![(vec![1, 3], 'a'), (vec![2, 4], 'b')]);
+}
+
+#[test]
+fn search_without_grouping() {
+    assert_eq!(
+        search_groups(&["ab", "ab"], size(2), letters()).unwrap(),
+        None
+    );
+    assert_eq!(
+        search_groups(&["ab", "ab", "a"], size(2), letters()).unwrap(),
+        None
+    );
+    assert_eq!(
+        search_groups(&["a1"], size(1), letters())
+            .unwrap_err()
+            .column,
+        2
+    );
+}
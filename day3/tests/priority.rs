@@ -0,0 +1,88 @@
+use aoc_day3::{
+    diagnose_with, find_badges, find_badges_with, process_sacks, process_sacks_with, PriorityMap,
+    Problem, MAX_ITEMS,
+};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+const GREEK: &str = include_str!("../alphabets/greek.toml");
+
+#[test]
+fn letters_match_puzzle() {
+    let letters = PriorityMap::default();
+    assert_eq!(letters.len(), 52);
+    assert_eq!(letters.priority('a'), Some(1));
+    assert_eq!(letters.priority('z'), Some(26));
+    assert_eq!(letters.priority('A'), Some(27));
+    assert_eq!(letters.priority('Z'), Some(52));
+    assert_eq!(letters.priority('1'), None);
+    assert_eq!(letters.item(28), Some('B'));
+    assert_eq!(letters.item(0), None);
+    assert_eq!(letters.item(53), None);
+
+    let sacks: Vec<&str> = SAMPLE.lines().collect();
+    assert_eq!(process_sacks_with(&sacks, &letters), process_sacks(&sacks));
+    assert_eq!(find_badges_with(&sacks, &letters), find_badges(&sacks));
+}
+
+#[test]
+fn digits() {
+    let digits = PriorityMap::from_toml("alphabet = \"0123456789\"").unwrap();
+    assert_eq!(digits.priority('0'), Some(1));
+    assert_eq!(
+        process_sacks_with(&["1231", "9089"], &digits).unwrap(),
+        2 + 10
+    );
+    assert_eq!(find_badges_with(&["123", "345", "35"], &digits).unwrap(), 4);
+
+    // Letters aren't items any more
+    let err = process_sacks_with(&["1a1a"], &digits).unwrap_err();
+    assert_eq!((err.line, err.column), (1, 2));
+}
+
+#[test]
+fn unicode_alphabet() {
+    let greek = PriorityMap::from_toml(GREEK).unwrap();
+    assert_eq!(greek.len(), 48);
+    assert_eq!(greek.priority('β'), Some(2));
+    assert_eq!(greek.priority('Ω'), Some(48));
+
+    // Compartments split by item rather than byte
+    assert_eq!(process_sacks_with(&["αΩβΩ"], &greek).unwrap(), 48);
+    let diagnosis = diagnose_with(&["αβγxα"], &greek);
+    let problems: Vec<Problem> = diagnosis
+        .diagnostics
+        .into_iter()
+        .map(|d| d.problem)
+        .collect();
+    assert_eq!(
+        problems,
+        vec![
+            Problem::OddLength { length: 5 },
+            Problem::InvalidItem {
+                column: 4,
+                item: 'x'
+            },
+        ]
+    );
+    assert_eq!(diagnosis.common_sum, 1);
+}
+
+#[test]
+fn invalid_alphabets() {
+    assert!(PriorityMap::new(" \n").is_err());
+    assert!(PriorityMap::new("abca").is_err());
+    assert!(PriorityMap::new("αβα").is_err());
+    assert!(PriorityMap::from_toml("letters = \"abc\"").is_err());
+
+    let most: String = ('!'..).take(MAX_ITEMS).collect();
+    assert_eq!(PriorityMap::new(&most).unwrap().len(), MAX_ITEMS);
+    let too_many: String = ('!'..).take(MAX_ITEMS + 1).collect();
+    assert!(PriorityMap::new(&too_many).is_err());
+}
+
+#[test]
+fn load_alphabet() {
+    let greek = PriorityMap::load("alphabets/greek.toml").unwrap();
+    assert_eq!(greek, PriorityMap::from_toml(GREEK).unwrap());
+    assert!(PriorityMap::load("alphabets/missing.toml").is_err());
+}
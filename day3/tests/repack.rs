@@ -0,0 +1,163 @@
+use std::collections::{HashSet, VecDeque};
+use std::num::NonZeroUsize;
+
+use aoc_common::Rng;
+use aoc_day3::{diagnose, plan_repack, Plan, PriorityMap, Swap, Swaps, GROUP_SIZE};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+fn repack(sacks: &[&str], swaps: Swaps) -> Plan {
+    plan_repack(sacks, swaps, PriorityMap::standard()).unwrap()
+}
+
+fn shared(sack: &str) -> usize {
+    let (first, second) = sack.split_at(sack.len() / 2);
+    let first: HashSet<char> = first.chars().collect();
+    second
+        .chars()
+        .filter(|c| first.contains(c))
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+// Fewest swaps between compartments by trying every sequence of swaps, `None` if none work
+fn brute_force(sack: &str) -> Option<usize> {
+    let mut seen = HashSet::from([sack.to_string()]);
+    let mut queue = VecDeque::from([(sack.to_string(), 0)]);
+    while let Some((sack, swaps)) = queue.pop_front() {
+        if shared(&sack) == 1 {
+            return Some(swaps);
+        }
+        let half = sack.len() / 2;
+        for i in 0..half {
+            for j in half..sack.len() {
+                let mut next: Vec<u8> = sack.bytes().collect();
+                next.swap(i, j);
+                let next = String::from_utf8(next).unwrap();
+                if seen.insert(next.clone()) {
+                    queue.push_back((next, swaps + 1));
+                }
+            }
+        }
+    }
+    None
+}
+
+#[test]
+fn sample_needs_no_swaps() {
+    let sacks: Vec<&str> = SAMPLE.lines().collect();
+    let plan = repack(&sacks, Swaps::Compartments);
+    assert!(plan.swaps.is_empty());
+    assert!(plan.unfixable.is_empty());
+    assert_eq!(plan.sacks, sacks);
+    assert_eq!(plan.common_sum, 157);
+    assert_eq!(plan.badge_sum, Some(70));
+    assert!(plan.optimal);
+}
+
+#[test]
+fn swaps_between_compartments() {
+    let plan = repack(&["abcabc", "abcc"], Swaps::Compartments);
+    assert_eq!(
+        plan.swaps,
+        vec![
+            Swap::Compartments {
+                sack: 1,
+                first: 'a',
+                second: 'b'
+            },
+            Swap::Compartments {
+                sack: 2,
+                first: 'a',
+                second: 'c'
+            },
+        ]
+    );
+    assert_eq!(plan.sacks, vec!["bbcaac", "cbac"]);
+    // c is shared by both sacks after the swaps
+    assert_eq!(plan.common_sum, 6);
+    assert_eq!(plan.badge_sum, None);
+}
+
+#[test]
+fn fewest_swaps() {
+    let items = ['a', 'b', 'c'];
+    // Every sack of up to 6 items from a, b and c
+    let mut sacks = vec![String::new()];
+    for _ in 0..6 {
+        sacks = sacks
+            .iter()
+            .flat_map(|sack| items.iter().map(move |item| format!("{}{}", sack, item)))
+            .collect();
+        for sack in sacks.iter().filter(|sack| sack.len() % 2 == 0) {
+            let plan = repack(&[sack], Swaps::Compartments);
+            let expected = brute_force(sack);
+            match expected {
+                Some(swaps) => {
+                    assert_eq!(plan.swaps.len(), swaps, "{}", sack);
+                    assert_eq!(shared(&plan.sacks[0]), 1, "{}", sack);
+                }
+                None => assert_eq!(plan.unfixable, vec![1], "{}", sack),
+            }
+        }
+    }
+}
+
+#[test]
+fn unfixable_sacks() {
+    // Two types of two items each, one has to be split and then so does the other
+    let plan = repack(&["aabb", "abab", "aa"], Swaps::Compartments);
+    assert_eq!(plan.unfixable, vec![1, 2]);
+    assert!(plan.swaps.is_empty());
+    assert_eq!(plan.sacks, vec!["aabb", "abab", "aa"]);
+    assert_eq!(plan.common_sum, 1);
+}
+
+#[test]
+fn trades_between_sacks() {
+    let sacks = ["aabb", "cccc", "dede"];
+    let plan = repack(&sacks, Swaps::Groups(GROUP_SIZE));
+    assert!(plan.unfixable.is_empty());
+    assert!(matches!(plan.swaps[0], Swap::Sacks { .. }));
+    // Trades are found greedily, so plans using them aren't proven to be the shortest
+    assert!(!plan.optimal);
+    let repacked: Vec<&str> = plan.sacks.iter().map(String::as_str).collect();
+    assert!(repacked.iter().all(|sack| shared(sack) == 1));
+    assert!(diagnose(&repacked).is_clean());
+
+    // Trading isn't worth it when every sack can be fixed with fewer swaps
+    let sacks = ["abcabc", "xyzx", "pqrp"];
+    let by_sack = repack(&sacks, Swaps::Compartments);
+    let grouped = repack(&sacks, Swaps::Groups(NonZeroUsize::new(3).unwrap()));
+    assert_eq!(grouped.swaps, by_sack.swaps);
+    assert_eq!(grouped.swaps.len(), 1);
+    assert!(grouped.optimal);
+}
+
+#[test]
+fn trades_never_cost_more_than_compartment_swaps() {
+    let items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut rng = Rng::new(2022);
+    let sacks: Vec<String> = (0..12)
+        .map(|_| (0..16).map(|_| items[rng.below(8)]).collect())
+        .collect();
+    let sacks: Vec<&str> = sacks.iter().map(String::as_str).collect();
+
+    let by_sack = repack(&sacks, Swaps::Compartments);
+    let grouped = repack(&sacks, Swaps::Groups(GROUP_SIZE));
+    assert!(grouped.unfixable.len() <= by_sack.unfixable.len());
+    if grouped.unfixable.len() == by_sack.unfixable.len() {
+        assert!(grouped.swaps.len() <= by_sack.swaps.len());
+    }
+    for (i, sack) in grouped.sacks.iter().enumerate() {
+        if !grouped.unfixable.contains(&(i + 1)) {
+            assert_eq!(shared(sack), 1, "{}", sack);
+        }
+    }
+}
+
+#[test]
+fn invalid_item() {
+    let err = plan_repack(&["ab1b"], Swaps::Compartments, PriorityMap::standard()).unwrap_err();
+    assert_eq!((err.line, err.column), (1, 3));
+}
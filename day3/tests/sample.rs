@@ -0,0 +1,25 @@
+use aoc_common::Solution;
+use aoc_day3::Day3;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn sample_part_1() {
+    let sacks = Day3::parse(SAMPLE).unwrap();
+    assert_eq!(Day3::part_1(&sacks).unwrap(), 157);
+}
+
+#[test]
+fn sample_part_2() {
+    let sacks = Day3::parse(SAMPLE).unwrap();
+    assert_eq!(Day3::part_2(&sacks).unwrap(), 70);
+}
+
+#[test]
+fn sample_saved_with_crlf_and_trailing_blank_lines() {
+    let input = format!("\u{feff}{}\r\n\r\n", SAMPLE.replace('\n', "\r\n"));
+    let expected = Day3::part_1(&Day3::parse(SAMPLE).unwrap()).unwrap();
+    let parsed = Day3::parse(&input).unwrap();
+    assert_eq!(Day3::part_1(&parsed).unwrap().to_string(), expected.to_string());
+    assert!(Day3::part_2(&parsed).is_ok());
+}
@@ -0,0 +1,113 @@
+use std::fmt::{Display, Formatter};
+
+use crate::SectionRange;
+
+// How every assignment together covers the sections
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Coverage {
+    // From the lowest covered section to the highest, `None` if there are no assignments
+    pub span: Option<SectionRange>,
+    // Gaps in the span that nobody is assigned, lowest first
+    pub uncovered: Vec<SectionRange>,
+    // Most assignments covering a single section
+    pub max_depth: usize,
+    // Every range of sections covered `max_depth` times, lowest first
+    pub deepest: Vec<SectionRange>,
+    // Sections covered at least once, however many times
+    pub covered: u64,
+}
+
+// Sweep over the start and end of every assignment in order, tracking how many assignments cover
+//      the sections between one and the next. Only the two ends of each assignment are kept, so
+//      this takes O(n log n) time for n assignments however many sections they cover.
+pub fn coverage(assignments: impl IntoIterator<Item = SectionRange>) -> Coverage {
+    // (section, change in depth) where an assignment starts covering or stops, one past its end.
+    //      Sections are widened so `end + 1` can't overflow.
+    let mut events: Vec<(i64, isize)> = Vec::new();
+    for range in assignments {
+        events.push((i64::from(range.start()), 1));
+        events.push((i64::from(range.end()) + 1, -1));
+    }
+    events.sort_unstable();
+
+    let mut coverage = Coverage::default();
+    let mut depth: isize = 0;
+    for (i, &(section, change)) in events.iter().enumerate() {
+        depth += change;
+        // Depth only matters once every event at this section has been counted
+        let Some(&(next, _)) = events.get(i + 1) else {
+            break;
+        };
+        if next == section {
+            continue;
+        }
+
+        // Events are at sections of an assignment or one past the end, so every section from
+        //      here up to the next event is within `i32`
+        let segment =
+            SectionRange::new(section as i32, (next - 1) as i32).expect("Events are in order");
+        if depth == 0 {
+            coverage.uncovered.push(segment);
+            continue;
+        }
+        coverage.covered += segment.length();
+        let depth = depth as usize;
+        if depth > coverage.max_depth {
+            coverage.max_depth = depth;
+            coverage.deepest.clear();
+        }
+        if depth == coverage.max_depth {
+            push_merged(&mut coverage.deepest, segment);
+        }
+    }
+
+    // Events are sorted, so the first is the lowest start and the last the highest end + 1
+    if let (Some(&(first, _)), Some(&(last, _))) = (events.first(), events.last()) {
+        coverage.span = SectionRange::new(first as i32, (last - 1) as i32);
+    }
+    coverage
+}
+
+// Add a segment that starts after every range in `ranges`, joining it to the last range if
+//      they're next to each other
+fn push_merged(ranges: &mut Vec<SectionRange>, segment: SectionRange) {
+    if let Some(last) = ranges.last_mut() {
+        if let [joined] = last.union(segment)[..] {
+            *last = joined;
+            return;
+        }
+    }
+    ranges.push(segment);
+}
+
+impl Display for Coverage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Some(span) = self.span else {
+            return writeln!(f, "No sections are assigned");
+        };
+        writeln!(
+            f,
+            "{} of the {} sections from {} are covered",
+            self.covered,
+            span.length(),
+            span
+        )?;
+        if !self.uncovered.is_empty() {
+            writeln!(f, "Nobody covers {}", join(&self.uncovered))?;
+        }
+        writeln!(
+            f,
+            "Up to {} assignments cover {}",
+            self.max_depth,
+            join(&self.deepest)
+        )
+    }
+}
+
+fn join(ranges: &[SectionRange]) -> String {
+    ranges
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
@@ -1,44 +1,60 @@
+use aoc_common::{ParseError, Solution};
+use eyre::Result;
+
+mod coverage;
+mod sections;
+
+pub use coverage::{coverage, Coverage};
+pub use sections::{Pair, SectionRange};
+
+const DAY: u8 = 4;
+
 // Method for solving part 1
-pub fn count_overlapping_assignments(pairs: &[&str]) -> i32 {
-    pairs.iter()
-        .map(is_overlapping)
-        .sum()
+pub fn count_overlapping_assignments(pairs: &[&str]) -> Result<i32, ParseError> {
+    Ok(count_fully_contained(&parse_pairs(pairs)?))
 }
 
-pub fn count_partial_overlaps(pairs: &[&str]) -> i32 {
-    pairs.iter()
-        .map(is_partial_overlap)
-        .sum()
+pub fn count_partial_overlaps(pairs: &[&str]) -> Result<i32, ParseError> {
+    Ok(count_overlaps(&parse_pairs(pairs)?))
 }
 
-fn is_overlapping(pair: &&str) -> i32 {
-    let (first,second) = pair.split_once(',')
-                                        .expect("Bad input, no ',' detected!");
+pub fn parse_pairs(pairs: &[&str]) -> Result<Vec<Pair>, ParseError> {
+    pairs.iter().enumerate().map(|(i, pair)| Pair::parse(i + 1, pair)).collect()
+}
 
-    let (first_section_1, first_section_2) = parse_sections(first);
-    let (second_section_1, second_section_2) = parse_sections(second);
+pub fn count_fully_contained(pairs: &[Pair]) -> i32 {
+    pairs.iter().filter(|pair| pair.fully_contains()).count() as i32
+}
 
-    ((first_section_1 <= second_section_1 && first_section_2 >= second_section_2) ||
-        (second_section_1 <= first_section_1 && second_section_2 >= first_section_2)).into()
+pub fn count_overlaps(pairs: &[Pair]) -> i32 {
+    pairs.iter().filter(|pair| pair.overlaps()).count() as i32
 }
 
-fn is_partial_overlap(pair: &&str) -> i32 {
-    let (first,second) = pair.split_once(',')
-                                        .expect("Bad input, no ',' detected!");
+// Coverage of both elves' assignments in every pair
+pub fn pair_coverage(pairs: &[Pair]) -> Coverage {
+    coverage(pairs.iter().flat_map(|pair| [pair.first, pair.second]))
+}
 
-    let (first_section_1, first_section_2) = parse_sections(first);
-    let (second_section_1, second_section_2) = parse_sections(second);
+pub struct Day4;
 
-    (first_section_1 <= second_section_2 && second_section_1 <= first_section_2)
-        .into()
-}
+impl Solution for Day4 {
+    const DAY: u8 = DAY;
 
-fn parse_sections(elf: &str) -> (i32, i32) {
-    let (section1,section2) = elf.split_once('-')
-                                            .expect("Bad input, no '-' detected!");
-    let section1: i32 = section1.parse().expect("Bad input, not a number");
-    let section2: i32 = section2.parse().expect("Bad input, not a number");
+    type Parsed = Vec<Pair>;
+    type Answer1 = i32;
+    type Answer2 = i32;
 
-    (section1, section2)
-}
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let input = aoc_common::normalize(input);
+        let pairs: Vec<&str> = input.lines().collect();
+        parse_pairs(&pairs)
+    }
 
+    fn part_1(pairs: &Self::Parsed) -> Result<Self::Answer1> {
+        Ok(count_fully_contained(pairs))
+    }
+
+    fn part_2(pairs: &Self::Parsed) -> Result<Self::Answer2> {
+        Ok(count_overlaps(pairs))
+    }
+}
@@ -0,0 +1,170 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use aoc_common::ParseError;
+
+use crate::DAY;
+
+// The sections assigned to an elf, every section from `start` to `end` inclusive, so a range always
+//      has at least one section. Written `start-end` like in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SectionRange {
+    start: i32,
+    end: i32,
+}
+
+impl SectionRange {
+    // `None` if the range ends before it starts
+    pub fn new(start: i32, end: i32) -> Option<Self> {
+        (start <= end).then_some(SectionRange { start, end })
+    }
+
+    // A range parsed from `range`, which is part of `text` on `line` of the input, for errors
+    pub fn parse(line: usize, text: &str, range: &str) -> Result<Self, ParseError> {
+        // A '-' at the very start is the sign of a negative start
+        let separator = range
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| c == '-')
+            .map(|(i, _)| i)
+            .ok_or_else(|| {
+                ParseError::at(DAY, line, text, range, "No '-' separating the sections")
+            })?;
+        let (start, end) = (&range[..separator], &range[separator + 1..]);
+        let start: i32 = start
+            .parse()
+            .map_err(|_| ParseError::at(DAY, line, text, start, "Section is not a number"))?;
+        let end: i32 = end
+            .parse()
+            .map_err(|_| ParseError::at(DAY, line, text, end, "Section is not a number"))?;
+        SectionRange::new(start, end)
+            .ok_or_else(|| ParseError::at(DAY, line, text, range, "Range ends before it starts"))
+    }
+
+    pub fn start(&self) -> i32 {
+        self.start
+    }
+
+    pub fn end(&self) -> i32 {
+        self.end
+    }
+
+    // Number of sections in the range
+    pub fn length(&self) -> u64 {
+        (i64::from(self.end) - i64::from(self.start) + 1) as u64
+    }
+
+    pub fn contains(&self, other: SectionRange) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn contains_section(&self, section: i32) -> bool {
+        self.start <= section && section <= self.end
+    }
+
+    pub fn overlaps(&self, other: SectionRange) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    // Sections in both ranges
+    pub fn intersection(&self, other: SectionRange) -> Option<SectionRange> {
+        SectionRange::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    // Sections in either range, as one range if they overlap or are next to each other and as
+    //      both otherwise, lowest first
+    pub fn union(&self, other: SectionRange) -> Vec<SectionRange> {
+        let (low, high) = if *self <= other {
+            (*self, other)
+        } else {
+            (other, *self)
+        };
+        if i64::from(high.start) <= i64::from(low.end) + 1 {
+            vec![SectionRange {
+                start: low.start,
+                end: low.end.max(high.end),
+            }]
+        } else {
+            vec![low, high]
+        }
+    }
+
+    // Sections in this range but not in `other`, split in two if `other` is in the middle of it
+    pub fn difference(&self, other: SectionRange) -> Vec<SectionRange> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+        // `other` overlaps, so the sections either side of it are still inside `i32`
+        let before = (other.start > self.start).then(|| SectionRange {
+            start: self.start,
+            end: other.start - 1,
+        });
+        let after = (other.end < self.end).then(|| SectionRange {
+            start: other.end + 1,
+            end: self.end,
+        });
+        before.into_iter().chain(after).collect()
+    }
+}
+
+impl FromStr for SectionRange {
+    type Err = ParseError;
+
+    // Errors are on line 1, use `SectionRange::parse` for ranges from a longer input
+    fn from_str(range: &str) -> Result<Self, Self::Err> {
+        SectionRange::parse(1, range, range)
+    }
+}
+
+impl Display for SectionRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+// The ranges assigned to a pair of elves, written `a-b,c-d`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pair {
+    pub first: SectionRange,
+    pub second: SectionRange,
+}
+
+impl Pair {
+    pub fn new(first: SectionRange, second: SectionRange) -> Self {
+        Pair { first, second }
+    }
+
+    pub fn parse(line: usize, pair: &str) -> Result<Self, ParseError> {
+        let (first, second) = pair
+            .split_once(',')
+            .ok_or_else(|| ParseError::line(DAY, line, pair, "No ',' separating the two elves"))?;
+        Ok(Pair {
+            first: SectionRange::parse(line, pair, first)?,
+            second: SectionRange::parse(line, pair, second)?,
+        })
+    }
+
+    // One elf's sections are all in the other's range
+    pub fn fully_contains(&self) -> bool {
+        self.first.contains(self.second) || self.second.contains(self.first)
+    }
+
+    pub fn overlaps(&self) -> bool {
+        self.first.overlaps(self.second)
+    }
+}
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    // Errors are on line 1, use `Pair::parse` for pairs from a longer input
+    fn from_str(pair: &str) -> Result<Self, Self::Err> {
+        Pair::parse(1, pair)
+    }
+}
+
+impl Display for Pair {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.first, self.second)
+    }
+}
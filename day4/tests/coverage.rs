@@ -0,0 +1,134 @@
+use aoc_common::Rng;
+use aoc_day4::{coverage, pair_coverage, parse_pairs, Coverage, SectionRange};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+fn range(start: i32, end: i32) -> SectionRange {
+    SectionRange::new(start, end).unwrap()
+}
+
+// Coverage by counting every section of every assignment
+fn brute_force(assignments: &[SectionRange]) -> Coverage {
+    let Some(low) = assignments.iter().map(|r| r.start()).min() else {
+        return Coverage::default();
+    };
+    let high = assignments.iter().map(|r| r.end()).max().unwrap();
+    let depths: Vec<usize> = (low..=high)
+        .map(|section| {
+            assignments
+                .iter()
+                .filter(|r| r.contains_section(section))
+                .count()
+        })
+        .collect();
+    let max_depth = depths.iter().copied().max().unwrap();
+
+    // Runs of sections with a depth matching `keep`
+    let runs = |keep: &dyn Fn(usize) -> bool| {
+        let mut runs: Vec<SectionRange> = Vec::new();
+        for (section, &depth) in (low..=high).zip(&depths) {
+            if !keep(depth) {
+                continue;
+            }
+            match runs.last_mut() {
+                Some(last) if last.end() + 1 == section => *last = range(last.start(), section),
+                _ => runs.push(range(section, section)),
+            }
+        }
+        runs
+    };
+    Coverage {
+        span: Some(range(low, high)),
+        uncovered: runs(&|depth| depth == 0),
+        max_depth,
+        deepest: runs(&|depth| depth == max_depth),
+        covered: depths.iter().filter(|&&depth| depth > 0).count() as u64,
+    }
+}
+
+// Deterministic spread of assignments, some overlapping and some leaving gaps
+fn assignments(count: usize, seed: u64) -> Vec<SectionRange> {
+    let mut rng = Rng::new(seed);
+    let mut next = |bound: usize| rng.below(bound) as i32;
+    (0..count)
+        .map(|_| {
+            let start = next(200) - 50;
+            range(start, start + next(12))
+        })
+        .collect()
+}
+
+#[test]
+fn sample_coverage() {
+    let lines: Vec<&str> = SAMPLE.lines().collect();
+    let coverage = pair_coverage(&parse_pairs(&lines).unwrap());
+    assert_eq!(coverage.span, Some(range(2, 9)));
+    assert!(coverage.uncovered.is_empty());
+    assert_eq!(coverage.covered, 8);
+    // Every assignment but 2-4, 2-3, 4-5 and 7-9 covers section 6
+    assert_eq!(coverage.max_depth, 8);
+    assert_eq!(coverage.deepest, vec![range(6, 6)]);
+}
+
+#[test]
+fn gaps_and_deepest_ranges() {
+    let coverage = coverage([
+        range(1, 3),
+        range(2, 4),
+        range(8, 9),
+        range(9, 10),
+        range(20, 20),
+    ]);
+    assert_eq!(coverage.span, Some(range(1, 20)));
+    assert_eq!(coverage.uncovered, vec![range(5, 7), range(11, 19)]);
+    assert_eq!(coverage.max_depth, 2);
+    assert_eq!(coverage.deepest, vec![range(2, 3), range(9, 9)]);
+    assert_eq!(coverage.covered, 4 + 3 + 1);
+    assert_eq!(
+        coverage.to_string(),
+        "8 of the 20 sections from 1-20 are covered\n\
+         Nobody covers 5-7, 11-19\n\
+         Up to 2 assignments cover 2-3, 9-9\n"
+    );
+}
+
+#[test]
+fn deepest_ranges_join_up() {
+    // Depth stays at 2 across the end of one assignment and the start of the next
+    let coverage = coverage([range(1, 10), range(1, 5), range(6, 10)]);
+    assert_eq!(coverage.deepest, vec![range(1, 10)]);
+}
+
+#[test]
+fn matches_brute_force() {
+    for seed in 0..20 {
+        let assignments = assignments(40, seed);
+        assert_eq!(
+            coverage(assignments.iter().copied()),
+            brute_force(&assignments),
+            "seed {}",
+            seed
+        );
+    }
+}
+
+#[test]
+fn no_assignments() {
+    assert_eq!(coverage([]), Coverage::default());
+    assert_eq!(coverage([]).to_string(), "No sections are assigned\n");
+}
+
+#[test]
+fn extreme_sections() {
+    let coverage = coverage([range(i32::MIN, -1), range(1, i32::MAX)]);
+    assert_eq!(coverage.uncovered, vec![range(0, 0)]);
+    assert_eq!(coverage.covered, (1 << 32) - 1);
+}
+
+#[test]
+fn millions_of_assignments() {
+    let assignments = assignments(1_000_000, 7);
+    let coverage = coverage(assignments);
+    assert_eq!(coverage.span, Some(range(-50, 160)));
+    assert!(coverage.max_depth > 1_000_000 / 200);
+}
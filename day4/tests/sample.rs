@@ -0,0 +1,25 @@
+use aoc_common::Solution;
+use aoc_day4::Day4;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn sample_part_1() {
+    let pairs = Day4::parse(SAMPLE).unwrap();
+    assert_eq!(Day4::part_1(&pairs).unwrap(), 2);
+}
+
+#[test]
+fn sample_part_2() {
+    let pairs = Day4::parse(SAMPLE).unwrap();
+    assert_eq!(Day4::part_2(&pairs).unwrap(), 4);
+}
+
+#[test]
+fn sample_saved_with_crlf_and_trailing_blank_lines() {
+    let input = format!("\u{feff}{}\r\n\r\n", SAMPLE.replace('\n', "\r\n"));
+    let expected = Day4::part_1(&Day4::parse(SAMPLE).unwrap()).unwrap();
+    let parsed = Day4::parse(&input).unwrap();
+    assert_eq!(Day4::part_1(&parsed).unwrap().to_string(), expected.to_string());
+    assert!(Day4::part_2(&parsed).is_ok());
+}
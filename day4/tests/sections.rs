@@ -0,0 +1,98 @@
+use aoc_day4::{
+    count_fully_contained, count_overlapping_assignments, count_overlaps, parse_pairs, Pair,
+    SectionRange,
+};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+fn range(start: i32, end: i32) -> SectionRange {
+    SectionRange::new(start, end).unwrap()
+}
+
+#[test]
+fn parse_range() {
+    let parsed: SectionRange = "2-8".parse().unwrap();
+    assert_eq!(parsed, range(2, 8));
+    assert_eq!((parsed.start(), parsed.end()), (2, 8));
+    assert_eq!(parsed.to_string(), "2-8");
+    assert_eq!("-3--1".parse::<SectionRange>().unwrap(), range(-3, -1));
+
+    let err = "8-2".parse::<SectionRange>().unwrap_err();
+    assert_eq!(err.reason, "Range ends before it starts");
+    let err = "2-x".parse::<SectionRange>().unwrap_err();
+    assert_eq!((err.column, err.text.as_str()), (3, "x"));
+    assert!("2".parse::<SectionRange>().is_err());
+    assert_eq!(SectionRange::new(3, 2), None);
+}
+
+#[test]
+fn parse_pair() {
+    let pair: Pair = "2-4,6-8".parse().unwrap();
+    assert_eq!(pair, Pair::new(range(2, 4), range(6, 8)));
+    assert_eq!(pair.to_string(), "2-4,6-8");
+
+    let err = Pair::parse(7, "2-4,6-x").unwrap_err();
+    assert_eq!((err.line, err.column), (7, 7));
+    let err = Pair::parse(2, "2-4").unwrap_err();
+    assert_eq!(err.reason, "No ',' separating the two elves");
+    let err = parse_pairs(&["1-2,3-4", "5-4,1-1"]).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 1));
+}
+
+#[test]
+fn length_and_contains() {
+    assert_eq!(range(3, 3).length(), 1);
+    assert_eq!(range(2, 8).length(), 7);
+    assert_eq!(range(i32::MIN, i32::MAX).length(), 1 << 32);
+
+    assert!(range(2, 8).contains(range(3, 7)));
+    assert!(range(2, 8).contains(range(2, 8)));
+    assert!(!range(3, 7).contains(range(2, 8)));
+    assert!(range(2, 8).contains_section(8));
+    assert!(!range(2, 8).contains_section(9));
+}
+
+#[test]
+fn overlaps_and_intersection() {
+    assert!(range(5, 7).overlaps(range(7, 9)));
+    assert!(!range(2, 4).overlaps(range(5, 6)));
+    assert_eq!(range(5, 7).intersection(range(7, 9)), Some(range(7, 7)));
+    assert_eq!(range(2, 8).intersection(range(3, 7)), Some(range(3, 7)));
+    assert_eq!(range(2, 4).intersection(range(6, 8)), None);
+}
+
+#[test]
+fn union() {
+    assert_eq!(range(5, 7).union(range(2, 6)), vec![range(2, 7)]);
+    // Sections are whole numbers, so ranges next to each other join up
+    assert_eq!(range(2, 3).union(range(4, 5)), vec![range(2, 5)]);
+    assert_eq!(
+        range(6, 8).union(range(2, 4)),
+        vec![range(2, 4), range(6, 8)]
+    );
+    assert_eq!(
+        range(i32::MIN, i32::MAX).union(range(0, 0)),
+        vec![range(i32::MIN, i32::MAX)]
+    );
+}
+
+#[test]
+fn difference() {
+    assert_eq!(
+        range(2, 8).difference(range(4, 5)),
+        vec![range(2, 3), range(6, 8)]
+    );
+    assert_eq!(range(2, 8).difference(range(0, 4)), vec![range(5, 8)]);
+    assert_eq!(range(2, 8).difference(range(6, 10)), vec![range(2, 5)]);
+    assert_eq!(range(2, 8).difference(range(1, 9)), vec![]);
+    assert_eq!(range(2, 4).difference(range(6, 8)), vec![range(2, 4)]);
+}
+
+#[test]
+fn counts_match_string_functions() {
+    let lines: Vec<&str> = SAMPLE.lines().collect();
+    let pairs = parse_pairs(&lines).unwrap();
+    assert_eq!(count_fully_contained(&pairs), 2);
+    assert_eq!(count_overlaps(&pairs), 4);
+    assert_eq!(count_overlapping_assignments(&lines).unwrap(), 2);
+}
@@ -1,7 +1,10 @@
 use std::collections::VecDeque;
+use aoc_common::{ParseError, Solution};
 use eyre::Result;
 use regex::Regex;
 
+const DAY: u8 = 5;
+
 type Stacks = Vec<VecDeque<char>>;
 
 pub struct Command {
@@ -10,10 +13,17 @@ pub struct Command {
     to: usize,
 }
 
-pub fn parse_input(input: &str) -> Result<(Stacks, Vec<Command>)> {
-    let (stacks, commands) = input.split_once("\r\n\r\n").unwrap();
-    let stacks = parse_stacks(stacks)?;
-    let commands = parse_commands(commands.trim());
+pub fn parse_input(input: &str) -> Result<(Stacks, Vec<Command>), ParseError> {
+    // The stacks are separated from the commands by a blank line, however the file was saved
+    let input = aoc_common::normalize(input);
+    let (stacks_str, commands) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::line(DAY, 1, input.lines().next().unwrap_or_default(),
+                         "No blank line separating the stacks from the commands")
+    })?;
+    let stacks = parse_stacks(stacks_str);
+    // Commands start after the stack drawing and the blank line following it
+    let first_line = stacks_str.lines().count() + 2;
+    let commands = parse_commands(commands, first_line, stacks.len())?;
 
     Ok((stacks, commands))
 }
@@ -46,12 +56,11 @@ pub fn get_top_of_stacks(stacks: &Stacks) -> String {
     stacks.iter().map_while(VecDeque::back).collect()
 }
 
-fn parse_stacks(stacks_str: &str) -> Result<Stacks> {
+fn parse_stacks(stacks_str: &str) -> Stacks {
     let mut stacks = Vec::new();
-    let mut lines = stacks_str.lines();
-    while let Some(line) = lines.next() {
+    for line in stacks_str.lines() {
         let chars: Vec<char> = line.trim_end().chars().collect();
-        if chars[1] == '1' {
+        if chars.get(1) == Some(&'1') {
             break;
         }
         let n = (chars.len() + 1) / 4;
@@ -59,26 +68,68 @@ fn parse_stacks(stacks_str: &str) -> Result<Stacks> {
             stacks.push(VecDeque::new());
         }
 
-        for i in 0..n {
+        for (i, stack) in stacks.iter_mut().enumerate().take(n) {
             let pos_char = i * 4 + 1;
             if chars[pos_char] != ' ' {
-                stacks[i].push_front(chars[pos_char]);
+                stack.push_front(chars[pos_char]);
             }
         }
     }
-    Ok(stacks)
+    stacks
 }
 
-fn parse_commands(commands_str: &str) -> Vec<Command> {
+fn parse_commands(commands_str: &str, first_line: usize, num_stacks: usize) -> Result<Vec<Command>, ParseError> {
     let r = Regex::new(r"move (\d+) from (\d+) to (\d+)").unwrap();
     commands_str.lines()
-        .map(|line| {
-            let cap = r.captures(line).unwrap();
-            Command {
-                qty: cap[1].parse().unwrap(),
-                from: cap[2].parse::<usize>().unwrap() - 1,
-                to: cap[3].parse::<usize>().unwrap() - 1,
-            }
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let line_no = first_line + i;
+            let cap = r.captures(line).ok_or_else(|| {
+                ParseError::line(DAY, line_no, line, "Expected 'move <qty> from <stack> to <stack>'")
+            })?;
+            let parse_field = |index: usize| -> Result<usize, ParseError> {
+                let field = cap.get(index).map_or("", |m| m.as_str());
+                field.parse().map_err(|_| ParseError::at(DAY, line_no, line, field, "Number is too large"))
+            };
+            let stack_index = |index: usize| -> Result<usize, ParseError> {
+                let stack = parse_field(index)?;
+                if stack == 0 || stack > num_stacks {
+                    let field = cap.get(index).map_or("", |m| m.as_str());
+                    return Err(ParseError::at(DAY, line_no, line, field,
+                                              format!("Stack must be between 1 and {}", num_stacks)));
+                }
+                Ok(stack - 1)
+            };
+            Ok(Command {
+                qty: parse_field(1)?,
+                from: stack_index(2)?,
+                to: stack_index(3)?,
+            })
     })
     .collect()
-}
\ No newline at end of file
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = DAY;
+
+    type Parsed = (Stacks, Vec<Command>);
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1((stacks, commands): &Self::Parsed) -> Result<Self::Answer1> {
+        let stacks = process_9000_commands(stacks.clone(), commands);
+        Ok(get_top_of_stacks(&stacks))
+    }
+
+    fn part_2((stacks, commands): &Self::Parsed) -> Result<Self::Answer2> {
+        let stacks = process_9001_commands(stacks.clone(), commands);
+        Ok(get_top_of_stacks(&stacks))
+    }
+}
@@ -1,23 +1,12 @@
-use std::fs;
-
 use aoc_day5 as AoC;
-use eyre::{WrapErr, Result};
+use eyre::Result;
 
 fn main() -> Result<()> {
-    use std::time::Instant;
-    let now = Instant::now();
-    {
-        let input = fs::read_to_string("day5/day5.txt")
-            .wrap_err("Failed to read input file!")?;
-        let (stacks, commands) = AoC::parse_input(&input.as_str())?;
-        println!("Parsed input. Elapsed: {:.2?}", now.elapsed());
-        let stacks = AoC::process_9000_commands(stacks, &commands);
-        println!("Processed commands. Elapsed: {:.2?}", now.elapsed());
-        let stack_tops = AoC::get_top_of_stacks(&stacks);
-        println!("Top of stacks: {:?}", stack_tops);
-    }
-    let elapsed = now.elapsed();
-    println!("Elapsed: {:.2?}", elapsed);
+    let input = aoc_common::read_input("day5/day5.txt")?;
+    let (stacks, commands) = AoC::parse_input(input.as_str())?;
+    let stacks = AoC::process_9000_commands(stacks, &commands);
+    let stack_tops = AoC::get_top_of_stacks(&stacks);
+    println!("Top of stacks: {:?}", stack_tops);
 
     Ok(())
 }
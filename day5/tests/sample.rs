@@ -0,0 +1,22 @@
+use aoc_common::Solution;
+use aoc_day5::Day5;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn sample_part_1() {
+    let parsed = Day5::parse(SAMPLE).unwrap();
+    assert_eq!(Day5::part_1(&parsed).unwrap(), "CMZ");
+}
+
+#[test]
+fn sample_part_2() {
+    let parsed = Day5::parse(SAMPLE).unwrap();
+    assert_eq!(Day5::part_2(&parsed).unwrap(), "MCD");
+}
+
+#[test]
+fn sample_with_crlf_line_endings() {
+    let parsed = Day5::parse(&SAMPLE.replace('\n', "\r\n")).unwrap();
+    assert_eq!(Day5::part_1(&parsed).unwrap(), "CMZ");
+}
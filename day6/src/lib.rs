@@ -1,8 +1,11 @@
+use aoc_common::{ParseError, Solution};
+use eyre::Result;
+
 // Solution for part 1
 pub fn find_packet_markers(signals: &[&str]) -> Vec<usize> {
     let mut markers = Vec::new();
     for signal in signals {
-        markers.push(find_first_marker(&signal, 4));
+        markers.push(find_first_marker(signal, 4));
     }
 
     markers
@@ -12,7 +15,7 @@ pub fn find_packet_markers(signals: &[&str]) -> Vec<usize> {
 pub fn find_message_markers(signals: &[&str]) -> Vec<usize> {
     let mut markers = Vec::new();
     for signal in signals {
-        markers.push(find_first_marker(&signal, 14));
+        markers.push(find_first_marker(signal, 14));
     }
 
     markers
@@ -20,7 +23,7 @@ pub fn find_message_markers(signals: &[&str]) -> Vec<usize> {
 
 fn find_first_marker(signal: &str, len: usize) -> usize {
     let bytes: Vec<u8> = signal.bytes().collect();
-    for i in 0..bytes.len() - len {
+    for i in 0..bytes.len().saturating_sub(len) {
         let mut quartet = vec![0; len];
         quartet.clone_from_slice(&bytes[i..i+len]);
         quartet.sort();
@@ -30,4 +33,48 @@ fn find_first_marker(signal: &str, len: usize) -> usize {
         }
     }
     0
-}
\ No newline at end of file
+}
+
+// Markers are reported for every signal in the input, comma separated
+fn format_markers(markers: &[usize]) -> String {
+    markers
+        .iter()
+        .map(usize::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Parsed = Vec<String>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let input = aoc_common::normalize(input);
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_1(signals: &Self::Parsed) -> Result<Self::Answer1> {
+        let signals: Vec<&str> = signals.iter().map(String::as_str).collect();
+        Ok(format_markers(&find_packet_markers(&signals)))
+    }
+
+    fn part_2(signals: &Self::Parsed) -> Result<Self::Answer2> {
+        let signals: Vec<&str> = signals.iter().map(String::as_str).collect();
+        Ok(format_markers(&find_message_markers(&signals)))
+    }
+
+    fn warnings(signals: &Self::Parsed) -> Vec<String> {
+        let signals: Vec<&str> = signals.iter().map(String::as_str).collect();
+        find_message_markers(&signals)
+            .iter()
+            .enumerate()
+            .filter(|(_, &marker)| marker == 0)
+            .map(|(i, _)| format!("Signal {} has no start-of-message marker", i + 1))
+            .collect()
+    }
+}
@@ -0,0 +1,37 @@
+use aoc_common::Solution;
+use aoc_day6::Day6;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn sample_packet_markers() {
+    let signals: Vec<&str> = SAMPLE.lines().collect();
+    assert_eq!(aoc_day6::find_packet_markers(&signals), vec![7, 5, 6, 10, 11]);
+}
+
+#[test]
+fn sample_message_markers() {
+    let signals: Vec<&str> = SAMPLE.lines().collect();
+    assert_eq!(aoc_day6::find_message_markers(&signals), vec![19, 23, 23, 29, 26]);
+}
+
+#[test]
+fn sample_part_1() {
+    let signals = Day6::parse(SAMPLE).unwrap();
+    assert_eq!(Day6::part_1(&signals).unwrap(), "7, 5, 6, 10, 11");
+}
+
+#[test]
+fn sample_part_2() {
+    let signals = Day6::parse(SAMPLE).unwrap();
+    assert_eq!(Day6::part_2(&signals).unwrap(), "19, 23, 23, 29, 26");
+}
+
+#[test]
+fn sample_saved_with_crlf_and_trailing_blank_lines() {
+    let input = format!("\u{feff}{}\r\n\r\n", SAMPLE.replace('\n', "\r\n"));
+    let expected = Day6::part_1(&Day6::parse(SAMPLE).unwrap()).unwrap();
+    let parsed = Day6::parse(&input).unwrap();
+    assert_eq!(Day6::part_1(&parsed).unwrap().to_string(), expected.to_string());
+    assert!(Day6::part_2(&parsed).is_ok());
+}
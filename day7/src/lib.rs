@@ -1,6 +1,9 @@
-use eyre::{eyre, Result, WrapErr};
+use aoc_common::{ParseError, Solution};
+use eyre::{eyre, Result};
 use std::collections::HashMap;
 
+const DAY: u8 = 7;
+
 pub type TreeIndex = usize;
 
 #[derive(Debug)]
@@ -9,6 +12,12 @@ pub struct Tree {
     root: Option<TreeIndex>,
 }
 
+impl Default for Tree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Tree {
     pub fn new() -> Self {
         Tree {
@@ -17,7 +26,7 @@ impl Tree {
         }
     }
 
-    pub fn iter(&mut self) -> PreorderIter {
+    pub fn iter(&self) -> PreorderIter {
         PreorderIter::new(self.root)
     }
 
@@ -28,7 +37,7 @@ impl Tree {
     pub fn add_node(&mut self, node: Node) -> TreeIndex {
         let index = self.arena.len();
         self.arena.push(Some(node));
-        return index;
+        index
     }
 
     pub fn remove_node_at(&mut self, index: TreeIndex) -> Option<Node> {
@@ -40,32 +49,48 @@ impl Tree {
     }
 
     pub fn node_at(&self, index: TreeIndex) -> Option<&Node> {
-        return if let Some(node) = self.arena.get(index) {
+        if let Some(node) = self.arena.get(index) {
             node.as_ref()
         } else {
             None
-        };
+        }
     }
 
     pub fn node_at_mut(&mut self, index: TreeIndex) -> Option<&mut Node> {
-        return if let Some(node) = self.arena.get_mut(index) {
+        if let Some(node) = self.arena.get_mut(index) {
             node.as_mut()
         } else {
             None
-        };
+        }
     }
 
     pub fn find_node(&self, name: &str) -> Option<TreeIndex> {
-        let mut index: TreeIndex = 0;
-        for node in self.arena.iter() {
-            if let Some(node) = node {
-                if node.name == name {
-                    return Some(index);
-                }
-                index += 1;
+        self.arena
+            .iter()
+            .position(|node| node.as_ref().is_some_and(|node| node.name == name))
+    }
+
+    // A child of the node at `parent_index` by name, names are only unique within a directory
+    pub fn find_child(&self, parent_index: TreeIndex, name: &str) -> Option<TreeIndex> {
+        self.node_at(parent_index)?
+            .children
+            .iter()
+            .copied()
+            .find(|&child| self.node_at(child).is_some_and(|node| node.name == name))
+    }
+
+    // Full path of the node at `index` from the root, like `/a/e`
+    pub fn path(&self, index: TreeIndex) -> String {
+        let mut names = Vec::new();
+        let mut current = Some(index);
+        while let Some(node) = current.and_then(|index| self.node_at(index)) {
+            if node.parent.is_some() {
+                names.push(node.name.as_str());
             }
+            current = node.parent;
         }
-        None
+        names.reverse();
+        format!("/{}", names.join("/"))
     }
 
     pub fn create_new_child(
@@ -74,6 +99,10 @@ impl Tree {
         name: &str,
         size: Option<i32>,
     ) -> Result<TreeIndex> {
+        // Listing a directory again doesn't add its entries twice
+        if let Some(index) = self.find_child(parent_index, name) {
+            return Ok(index);
+        }
         let new_child = Node::new(name.to_string(), size, Vec::new(), Some(parent_index));
         let index = self.add_node(new_child);
         let parent = self
@@ -99,15 +128,12 @@ impl PreorderIter {
     }
 
     pub fn next(&mut self, tree: &Tree) -> Option<TreeIndex> {
-        while let Some(node_index) = self.stack.pop() {
-            if let Some(node) = tree.node_at(node_index) {
-                self.stack.append(node.children.clone().as_mut())
-            }
-
-            return Some(node_index);
+        let node_index = self.stack.pop()?;
+        if let Some(node) = tree.node_at(node_index) {
+            self.stack.append(node.children.clone().as_mut())
         }
 
-        None
+        Some(node_index)
     }
 }
 
@@ -135,7 +161,7 @@ impl Node {
     }
 }
 
-pub fn parse_tree(input: String) -> Result<Tree> {
+pub fn parse_tree(input: String) -> Result<Tree, ParseError> {
     let mut tree = Tree::new();
     let root = tree.add_node(Node {
         name: "/".to_string(),
@@ -144,68 +170,70 @@ pub fn parse_tree(input: String) -> Result<Tree> {
         parent: None,
     });
     tree.set_root(Some(root));
-    Ok(parse_commands(input, tree)?)
+    parse_commands(input, tree)
 }
 
-fn parse_commands(input: String, mut tree: Tree) -> Result<Tree> {
+fn parse_commands(input: String, mut tree: Tree) -> Result<Tree, ParseError> {
     let mut current_node_index: TreeIndex = 0;
-    for line in input.lines() {
-        let mut parts = line.trim_start_matches("$").split_whitespace();
-        let prefix = parts.next().ok_or_else(|| eyre!("No prefix found!"))?;
+    for (i, line) in input.lines().enumerate() {
+        let line_no = i + 1;
+        let mut parts = line.trim_start_matches('$').split_whitespace();
+        let prefix = parts
+            .next()
+            .ok_or_else(|| ParseError::line(DAY, line_no, line, "No prefix found"))?;
         let suffix = parts.next();
+        let name = || suffix.ok_or_else(|| ParseError::line(DAY, line_no, line, "No name found"));
         match prefix {
             "cd" => {
-                let name = suffix.ok_or_else(|| eyre!("No suffix found!"))?;
-                current_node_index = process_cd(name, current_node_index, &tree)?;
+                let name = name()?;
+                current_node_index = process_cd(name, current_node_index, &tree)
+                    .map_err(|e| ParseError::at(DAY, line_no, line, name, e.to_string()))?;
             }
             "ls" => continue,
             "dir" => {
-                let name = suffix.ok_or_else(|| eyre!("No suffix found!"))?;
-                tree.create_new_child(current_node_index, name, None)?;
+                let name = name()?;
+                tree.create_new_child(current_node_index, name, None)
+                    .map_err(|e| ParseError::at(DAY, line_no, line, name, e.to_string()))?;
             }
             _ => {
-                let size: i32 = prefix.parse().wrap_err("Unable to parse file size!")?;
-                let name = suffix.ok_or_else(|| eyre!("No suffix found!"))?;
-                tree.create_new_child(current_node_index, name, Some(size))?;
+                let size: i32 = prefix.parse().map_err(|_| {
+                    ParseError::at(DAY, line_no, line, prefix, "Unable to parse file size")
+                })?;
+                let name = name()?;
+                tree.create_new_child(current_node_index, name, Some(size))
+                    .map_err(|e| ParseError::at(DAY, line_no, line, name, e.to_string()))?;
             }
         }
     }
     Ok(tree)
 }
 
-fn process_cd(name: &str, mut current_node_index: TreeIndex, tree: &Tree) -> Result<TreeIndex> {
-    if name == ".." {
-        if current_node_index == 0 {
-            return Ok(current_node_index);
+fn process_cd(name: &str, current_node_index: TreeIndex, tree: &Tree) -> Result<TreeIndex> {
+    match name {
+        "/" => tree.root.ok_or_else(|| eyre!("The tree has no root!")),
+        ".." => {
+            let node = tree
+                .node_at(current_node_index)
+                .ok_or_else(|| eyre!("No node found at {}!", current_node_index))?;
+            // Moving up from the root stays there
+            Ok(node.parent.unwrap_or(current_node_index))
         }
-        current_node_index = tree
-            .node_at(current_node_index)
-            .ok_or_else(|| eyre!("No node found at {}!", current_node_index))?
-            .parent
-            .ok_or_else(|| {
-                eyre!(
-                    "No parent found when switching from index {} to {}",
-                    current_node_index,
-                    name
-                )
-            })?;
-    } else {
-        current_node_index = tree
-            .find_node(name)
-            .ok_or_else(|| eyre!("Node {} not found!", name))?
-    }
-    Ok(current_node_index)
+        _ => tree
+            .find_child(current_node_index, name)
+            .filter(|&index| tree.node_at(index).is_some_and(|node| node.size.is_none()))
+            .ok_or_else(|| eyre!("No directory {} in {}", name, tree.path(current_node_index))),
+    }
 }
 
-pub fn sum_directories(mut tree: Tree) -> HashMap<String, i32> {
+// Total size of every directory keyed by its full path, so directories with the same name in
+//      different places are kept apart
+pub fn sum_directories(tree: &Tree) -> HashMap<String, i32> {
     let mut results = HashMap::new();
     let mut iter = tree.iter();
-    while let Some(node_index) = iter.next(&tree) {
+    while let Some(node_index) = iter.next(tree) {
         if let Some(node) = tree.node_at(node_index) {
-            if node.size == None {
-                let entry = results.entry(node.name.clone()).or_insert(0);
-                let size = calc_dir_size(node_index, &tree);
-                *entry = size;
+            if node.size.is_none() {
+                results.insert(tree.path(node_index), calc_dir_size(node_index, tree));
             }
         }
     }
@@ -217,8 +245,48 @@ fn calc_dir_size(node_index: TreeIndex, tree: &Tree) -> i32 {
     if let Some(node) = tree.node_at(node_index) {
         size += node.size.unwrap_or(0);
         for child in &node.children {
-            size += calc_dir_size(child.clone(), &tree);
+            size += calc_dir_size(*child, tree);
         }
     }
     size
 }
+
+// Solution for part 2, find the size of the smallest directory that frees up enough space when deleted
+pub fn find_dir_to_delete(
+    sums: &HashMap<String, i32>,
+    disk_size: i32,
+    required: i32,
+) -> Result<i32> {
+    let used = sums
+        .get("/")
+        .ok_or_else(|| eyre!("No root directory found!"))?;
+    let to_free = required - (disk_size - used);
+    sums.values()
+        .filter(|&&size| size >= to_free)
+        .min()
+        .copied()
+        .ok_or_else(|| eyre!("No directory is large enough to free {}", to_free))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = DAY;
+
+    type Parsed = Tree;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_tree(aoc_common::normalize(input).into_owned())
+    }
+
+    fn part_1(tree: &Self::Parsed) -> Result<Self::Answer1> {
+        let sums = sum_directories(tree);
+        Ok(sums.values().filter(|&&size| size <= 100000).sum())
+    }
+
+    fn part_2(tree: &Self::Parsed) -> Result<Self::Answer2> {
+        find_dir_to_delete(&sum_directories(tree), 70000000, 30000000)
+    }
+}
@@ -0,0 +1,55 @@
+use aoc_common::Solution;
+use aoc_day7::Day7;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn sample_directory_sums() {
+    let tree = aoc_day7::parse_tree(SAMPLE.to_string()).unwrap();
+    let sums = aoc_day7::sum_directories(&tree);
+    assert_eq!(sums.len(), 4);
+    assert_eq!(sums["/a/e"], 584);
+    assert_eq!(sums["/a"], 94853);
+    assert_eq!(sums["/d"], 24933642);
+    assert_eq!(sums["/"], 48381165);
+}
+
+#[test]
+fn sample_part_1() {
+    let tree = Day7::parse(SAMPLE).unwrap();
+    assert_eq!(Day7::part_1(&tree).unwrap(), 95437);
+}
+
+#[test]
+fn sample_part_2() {
+    let tree = Day7::parse(SAMPLE).unwrap();
+    assert_eq!(Day7::part_2(&tree).unwrap(), 24933642);
+}
+
+#[test]
+fn directories_with_the_same_name() {
+    let input = "$ cd /\n$ ls\ndir a\ndir b\n$ cd a\n$ ls\ndir x\n$ cd x\n$ ls\n100 f\n\
+                 $ cd ..\n$ cd ..\n$ cd b\n$ ls\ndir x\n$ cd x\n$ ls\n7 g\n";
+    let tree = aoc_day7::parse_tree(input.to_string()).unwrap();
+    let sums = aoc_day7::sum_directories(&tree);
+    assert_eq!(sums.len(), 5);
+    assert_eq!(sums["/a/x"], 100);
+    assert_eq!(sums["/b/x"], 7);
+    assert_eq!(sums["/"], 107);
+    assert_eq!(Day7::part_1(&tree).unwrap(), 100 + 7 + 100 + 7 + 107);
+}
+
+#[test]
+fn cd_into_unknown_directory() {
+    let err = aoc_day7::parse_tree("$ cd /\n$ ls\ndir a\n$ cd b\n".to_string()).unwrap_err();
+    assert_eq!(err.line, 4);
+}
+
+#[test]
+fn sample_saved_with_crlf_and_trailing_blank_lines() {
+    let input = format!("\u{feff}{}\r\n\r\n", SAMPLE.replace('\n', "\r\n"));
+    let expected = Day7::part_1(&Day7::parse(SAMPLE).unwrap()).unwrap();
+    let parsed = Day7::parse(&input).unwrap();
+    assert_eq!(Day7::part_1(&parsed).unwrap().to_string(), expected.to_string());
+    assert!(Day7::part_2(&parsed).is_ok());
+}
@@ -1,5 +1,8 @@
+use aoc_common::{ParseError, Solution};
 use eyre::{eyre, Result};
 
+const DAY: u8 = 8;
+
 type TreeGrid = Vec<Vec<i32>>;
 
 #[derive(Debug)]
@@ -8,16 +11,25 @@ pub struct TreeCoordinate {
     y: usize,
 }
 
-pub fn parse_grid(input: &str) -> Result<TreeGrid> {
+pub fn parse_grid(input: &str) -> Result<TreeGrid, ParseError> {
     let mut grid = TreeGrid::new();
-    for line in input.lines() {
+    for (i, line) in input.lines().enumerate() {
         let chars = line.chars();
         let mut row = Vec::new();
-        for char in chars {
+        for (column, char) in chars.enumerate() {
             let tree = char.to_digit(10)
-                .ok_or_else(|| eyre!("Could not parse tree"))?;
+                .ok_or_else(|| {
+                    ParseError::new(DAY, i + 1, column + 1, char.to_string(), "Tree height must be a digit")
+                })?;
             row.push(tree as i32);
         }
+        // Every row has to be as wide as the first, pointing at where a row ends early or runs long
+        let width = grid.first().map_or(row.len(), Vec::len);
+        if row.is_empty() || row.len() != width {
+            let column = row.len().min(width) + 1;
+            let reason = format!("Row is {} trees wide, expected {}", row.len(), width.max(1));
+            return Err(ParseError::new(DAY, i + 1, column, line, reason));
+        }
         grid.push(row);
     }
     Ok(grid)
@@ -29,7 +41,7 @@ pub fn find_visible_trees(grid: &TreeGrid) -> Vec<TreeCoordinate> {
     for y in 0..grid.len() {
         for x in 0..grid[y].len() {
             let loc = TreeCoordinate { x, y };
-            if is_visible(&loc, &grid) {
+            if is_visible(&loc, grid) {
                 visible_trees.push(loc);
             }
         }
@@ -52,7 +64,7 @@ fn is_visible(loc: &TreeCoordinate, grid: &TreeGrid) -> bool {
     let vis_right = row[loc.x + 1..].iter().all(|size| size < &tree);
 
     // Vertical checks
-    let col = get_column(&grid, loc.x);
+    let col = get_column(grid, loc.x);
     // Check top
     let vis_top = col[..loc.y].iter().all(|&size| size < tree);
     //Check bottom
@@ -63,7 +75,7 @@ fn is_visible(loc: &TreeCoordinate, grid: &TreeGrid) -> bool {
 
 fn get_column(grid: &TreeGrid, index: usize) -> Vec<i32> {
     grid.iter()
-        .map(|r| *r.iter().nth(index).unwrap())
+        .map(|r| r[index])
         .collect::<Vec<_>>()
 }
 
@@ -87,7 +99,7 @@ fn calc_scenic_score(loc: &TreeCoordinate, grid: &TreeGrid) -> i32 {
         return 0
     }
     let row = &grid[loc.y];
-    let col = get_column(&grid, loc.x);
+    let col = get_column(grid, loc.x);
     let tree = grid[loc.y][loc.x];
     let horizontal_score = calc_horizontal(loc, tree, row);
     let vertical_score = calc_vertical(loc, tree, &col);
@@ -95,7 +107,7 @@ fn calc_scenic_score(loc: &TreeCoordinate, grid: &TreeGrid) -> i32 {
     horizontal_score * vertical_score
 }
 
-fn calc_horizontal(loc: &TreeCoordinate, tree: i32, row: &Vec<i32>) -> i32 {
+fn calc_horizontal(loc: &TreeCoordinate, tree: i32, row: &[i32]) -> i32 {
     let mut blocked = false;
     let left = row[..=loc.x-1].iter().rev().fold(0, |accum, size| {
         if !blocked {
@@ -127,7 +139,7 @@ fn calc_horizontal(loc: &TreeCoordinate, tree: i32, row: &Vec<i32>) -> i32 {
     left * right
 }
 
-fn calc_vertical(loc: &TreeCoordinate, tree: i32, col: &Vec<i32>) -> i32 {
+fn calc_vertical(loc: &TreeCoordinate, tree: i32, col: &[i32]) -> i32 {
     let mut blocked = false;
     let top = col[..=loc.y-1].iter().rev().fold(0, |accum , size | {
         if !blocked {
@@ -156,4 +168,26 @@ fn calc_vertical(loc: &TreeCoordinate, tree: i32, col: &Vec<i32>) -> i32 {
         }
     });
     top * bottom
-}
\ No newline at end of file
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = DAY;
+
+    type Parsed = TreeGrid;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_grid(&aoc_common::normalize(input))
+    }
+
+    fn part_1(grid: &Self::Parsed) -> Result<Self::Answer1> {
+        Ok(find_visible_trees(grid).len())
+    }
+
+    fn part_2(grid: &Self::Parsed) -> Result<Self::Answer2> {
+        find_max_scenic_score(grid)
+    }
+}
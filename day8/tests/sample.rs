@@ -0,0 +1,36 @@
+use aoc_common::Solution;
+use aoc_day8::Day8;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn sample_part_1() {
+    let grid = Day8::parse(SAMPLE).unwrap();
+    assert_eq!(Day8::part_1(&grid).unwrap(), 21);
+}
+
+#[test]
+fn sample_part_2() {
+    let grid = Day8::parse(SAMPLE).unwrap();
+    assert_eq!(Day8::part_2(&grid).unwrap(), 8);
+}
+
+#[test]
+fn ragged_rows_are_rejected() {
+    let input = SAMPLE.replacen("25512", "2551", 1);
+    let error = Day8::parse(&input).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 5));
+    assert_eq!(error.reason, "Row is 4 trees wide, expected 5");
+
+    let error = Day8::parse(&SAMPLE.replacen('\n', "\n\n", 1)).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+}
+
+#[test]
+fn sample_saved_with_crlf_and_trailing_blank_lines() {
+    let input = format!("\u{feff}{}\r\n\r\n", SAMPLE.replace('\n', "\r\n"));
+    let expected = Day8::part_1(&Day8::parse(SAMPLE).unwrap()).unwrap();
+    let parsed = Day8::parse(&input).unwrap();
+    assert_eq!(Day8::part_1(&parsed).unwrap().to_string(), expected.to_string());
+    assert!(Day8::part_2(&parsed).is_ok());
+}
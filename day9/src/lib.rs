@@ -1,8 +1,11 @@
 use core::{f32, fmt};
 use std::collections::VecDeque;
 
+use aoc_common::{ParseError, Solution};
 use eyre::{eyre, Result};
 
+const DAY: u8 = 9;
+
 #[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Clone)]
 pub struct Coord {
     x: i16,
@@ -65,19 +68,29 @@ pub enum Direction {
     Right,
 }
 
-pub fn parse_input(input: String) -> Result<Vec<Move>> {
+pub fn parse_input(input: String) -> Result<Vec<Move>, ParseError> {
     let mut moves = Vec::new();
-    for line in input.lines() {
-        let (dir, delta) = line
-            .split_once(" ")
-            .ok_or_else(|| eyre!("Unable to split line."))?;
-        let delta = delta.parse::<i16>()?;
+    for (i, line) in input.lines().enumerate() {
+        let (dir, delta) = line.split_once(' ').ok_or_else(|| {
+            ParseError::line(DAY, i + 1, line, "Expected a direction and a distance")
+        })?;
+        let delta = delta
+            .parse::<i16>()
+            .map_err(|_| ParseError::at(DAY, i + 1, line, delta, "Distance must be a number"))?;
         let mv = match dir {
             "U" => Move::new(Direction::Up, delta),
             "D" => Move::new(Direction::Down, delta),
             "L" => Move::new(Direction::Left, delta),
             "R" => Move::new(Direction::Right, delta),
-            _ => panic!("Unrecognized input!"),
+            _ => {
+                return Err(ParseError::at(
+                    DAY,
+                    i + 1,
+                    line,
+                    dir,
+                    "Direction must be U, D, L or R",
+                ));
+            }
         };
         moves.push(mv);
     }
@@ -95,7 +108,6 @@ pub fn part_1(moves: &Vec<Move>) -> Vec<Coord> {
             tail_locations.push(Coord::new(tail.x, tail.y));
         }
     }
-    println!("Final Locations:\nHead:\t{}\nTail:\t{}", head, tail);
     tail_locations.sort();
     tail_locations.dedup();
     tail_locations
@@ -108,8 +120,8 @@ pub fn part_2(moves: &Vec<Move>, rope_len: usize) -> Result<Vec<Coord>> {
     for mv in moves {
         for _ in 0..mv.delta {
             {
-                let mut head = rope.front_mut().ok_or_else(|| eyre!("Rope is empty!"))?;
-                move_head(&mut head, &mv.dir);
+                let head = rope.front_mut().ok_or_else(|| eyre!("Rope is empty!"))?;
+                move_head(head, &mv.dir);
             }
             for i in 1..rope_len {
                 let prev = rope
@@ -125,11 +137,6 @@ pub fn part_2(moves: &Vec<Move>, rope_len: usize) -> Result<Vec<Coord>> {
             tail_locations.push(Coord::new(tail.x, tail.y))
         }
     }
-    println!(
-        "Final locations:\n{}",
-        rope.iter()
-            .fold(String::new(), |acc, coord| acc + &coord.to_string() + "\n")
-    );
     tail_locations.sort();
     tail_locations.dedup();
     Ok(tail_locations)
@@ -207,3 +214,25 @@ fn move_knot(knot: &mut Coord, prev: &Coord) {
         }
     }
 }
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = DAY;
+
+    type Parsed = Vec<Move>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(aoc_common::normalize(input).into_owned())
+    }
+
+    fn part_1(moves: &Self::Parsed) -> Result<Self::Answer1> {
+        Ok(part_1(moves).len())
+    }
+
+    fn part_2(moves: &Self::Parsed) -> Result<Self::Answer2> {
+        Ok(part_2(moves, 10)?.len())
+    }
+}
@@ -0,0 +1,39 @@
+use aoc_common::Solution;
+use aoc_day9::Day9;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+const SAMPLE_2: &str = include_str!("../sample2.txt");
+
+#[test]
+fn sample_part_1() {
+    let moves = Day9::parse(SAMPLE).unwrap();
+    assert_eq!(Day9::part_1(&moves).unwrap(), 13);
+}
+
+#[test]
+fn sample_part_2() {
+    let moves = Day9::parse(SAMPLE).unwrap();
+    assert_eq!(Day9::part_2(&moves).unwrap(), 1);
+}
+
+#[test]
+fn sample_2_part_2() {
+    let moves = Day9::parse(SAMPLE_2).unwrap();
+    assert_eq!(Day9::part_2(&moves).unwrap(), 36);
+}
+
+#[test]
+fn invalid_direction_is_located() {
+    let error = Day9::parse("R 4\nU 4\nX 3\n").unwrap_err();
+    assert_eq!((error.day, error.line, error.column), (9, 3, 1));
+    assert_eq!(error.text, "X");
+}
+
+#[test]
+fn sample_saved_with_crlf_and_trailing_blank_lines() {
+    let input = format!("\u{feff}{}\r\n\r\n", SAMPLE.replace('\n', "\r\n"));
+    let expected = Day9::part_1(&Day9::parse(SAMPLE).unwrap()).unwrap();
+    let parsed = Day9::parse(&input).unwrap();
+    assert_eq!(Day9::part_1(&parsed).unwrap().to_string(), expected.to_string());
+    assert!(Day9::part_2(&parsed).is_ok());
+}